@@ -0,0 +1,11 @@
+# Only loaded with the `fluent` feature
+-brand = Bevy
+
+fluent-welcome = Welcome to { -brand }, { $name }!
+fluent-emails =
+    { $count ->
+        [one] You have one email
+       *[other] You have { $count } emails
+    }
+fluent-login = Log in
+    .placeholder = Email address
@@ -0,0 +1,73 @@
+//! Measures a locale switch with the texts translated serially and in parallel,
+//! to pick the default of `I18nPlugin::with_parallel_threshold`
+//!
+//! `cargo bench --bench translations`, the parallel update needs Bevy's `multi_threaded` feature
+//! and uses every core unless `BEVY_I18N_BENCH_THREADS` is set
+
+use std::time::{Duration, Instant};
+
+use bevy::{core::TaskPoolOptions, prelude::*, tasks::ComputeTaskPool};
+use bevy_simple_i18n::prelude::*;
+
+const TEXTS: [usize; 7] = [250, 500, 1_000, 2_000, 4_000, 8_000, 16_000];
+const SWITCHES: u32 = 20;
+
+fn app(texts: usize, parallel_threshold: usize) -> App {
+    let threads = std::env::var("BEVY_I18N_BENCH_THREADS")
+        .ok()
+        .and_then(|threads| threads.parse().ok());
+    let task_pool_options = threads.map_or_else(TaskPoolOptions::default, |threads| {
+        TaskPoolOptions::with_num_threads(threads)
+    });
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins.set(TaskPoolPlugin { task_pool_options }),
+        AssetPlugin::default(),
+    ))
+    .init_asset::<Font>()
+    .add_plugins(
+        I18nPlugin::new()
+            .with_default_locale("en")
+            .with_parallel_threshold(parallel_threshold),
+    );
+    for index in 0..texts {
+        app.world_mut().spawn((
+            Text::default(),
+            I18nText::new("messages.hello").with_arg("name", index),
+        ));
+    }
+    app.update();
+    app
+}
+
+/// Average duration of the frame that follows a locale switch
+fn measure(texts: usize, parallel_threshold: usize) -> Duration {
+    let mut app = app(texts, parallel_threshold);
+    let mut total = Duration::ZERO;
+    for switch in 0..SWITCHES {
+        let locale = if switch % 2 == 0 { "ja" } else { "en" };
+        app.world_mut()
+            .resource_mut::<I18n>()
+            .set_locale(locale)
+            .unwrap();
+        let start = Instant::now();
+        app.update();
+        total += start.elapsed();
+    }
+    total / SWITCHES
+}
+
+fn main() {
+    // Initializes the task pools
+    drop(app(0, usize::MAX));
+    println!("{} compute threads", ComputeTaskPool::get().thread_num());
+    println!("{:>8} {:>12} {:>12}", "texts", "serial", "parallel");
+    for texts in TEXTS {
+        println!(
+            "{:>8} {:>12.2?} {:>12.2?}",
+            texts,
+            measure(texts, usize::MAX),
+            measure(texts, 0)
+        );
+    }
+}
@@ -5,16 +5,145 @@ use std::{
     path::{Path, PathBuf},
 };
 
+#[path = "build/csv.rs"]
+mod csv;
+#[path = "build/fluent.rs"]
+mod fluent;
+#[path = "build/gettext.rs"]
+mod gettext;
+#[path = "build/locale_path.rs"]
+mod locale_path;
+#[path = "build/translations.rs"]
+mod translations;
+
 const ASSET_PATH_VAR: &str = "BEVY_ASSET_PATH";
+/// Locale that the keys of the other locales are compared to at build time, the check is skipped when it's not set
+const REFERENCE_LOCALE_VAR: &str = "BEVY_I18N_REFERENCE_LOCALE";
+/// Extra folders of locale files merged over the `assets/locales` folder, e.g. for DLCs and mods,
+/// separated like the `PATH` and relative to the crate root. The later folders override the keys of the earlier ones
+const LOCALE_ROOTS_VAR: &str = "BEVY_I18N_LOCALE_ROOTS";
+/// Set to `1` or `true` to fail the build instead of warning when the keys of the locales differ from the reference locale
+const DENY_MISSING_KEYS_VAR: &str = "BEVY_I18N_DENY_MISSING_KEYS";
+/// Header of the key column of the CSV files, `key` when it's not set
+const CSV_KEY_COLUMN_VAR: &str = "BEVY_I18N_CSV_KEY_COLUMN";
+/// Comma-separated headers of the locale columns of the CSV files, every column named after a locale when it's not set
+const CSV_LOCALE_COLUMNS_VAR: &str = "BEVY_I18N_CSV_LOCALE_COLUMNS";
 const OUTPUT_FILE_NAME: &str = "bevy_simple_i18n.rs";
-const ALLOWED_EXTENSIONS: &[&str] = &["otf", "ttf"];
+const KEYS_FILE_NAME: &str = "bevy_simple_i18n_keys.rs";
+const KEYS_MODULE_FILE_NAME: &str = "bevy_simple_i18n_keys_module.rs";
+const BACKENDS_FILE_NAME: &str = "bevy_simple_i18n_backends.rs";
+const GLYPHS_FILE_NAME: &str = "bevy_simple_i18n_glyphs.rs";
+const LISTS_FILE_NAME: &str = "bevy_simple_i18n_lists.rs";
+const ROOTS_FILE_NAME: &str = "bevy_simple_i18n_roots.rs";
+const PLACEHOLDERS_FILE_NAME: &str = "bevy_simple_i18n_placeholders.rs";
+const LOCALE_KEYS_FILE_NAME: &str = "bevy_simple_i18n_locale_keys.rs";
+const LOCALIZED_ASSETS_FILE_NAME: &str = "bevy_simple_i18n_localized_assets.rs";
+const AUDIO_FILE_NAME: &str = "bevy_simple_i18n_audio.rs";
+/// Extensions of the font files, including the `otc` and `ttc` collections
+const ALLOWED_EXTENSIONS: &[&str] = &["otc", "otf", "ttc", "ttf"];
+/// Extensions of the locale clips of the audio folders, `voice/greeting/fr.ogg`
+const AUDIO_EXTENSIONS: &[&str] = &["flac", "mp3", "oga", "ogg", "spx", "wav"];
+/// Optional file of a font folder mapping locales to a font size multiplier, `ja = 0.9`
+const SCALES_FILE_NAME: &str = "scales.toml";
 
 fn main() {
     cargo_emit::rerun_if_env_changed!(ASSET_PATH_VAR);
+    cargo_emit::rerun_if_env_changed!(LOCALE_ROOTS_VAR);
+    cargo_emit::rerun_if_env_changed!(REFERENCE_LOCALE_VAR);
+    cargo_emit::rerun_if_env_changed!(DENY_MISSING_KEYS_VAR);
+    cargo_emit::rerun_if_env_changed!(CSV_KEY_COLUMN_VAR);
+    cargo_emit::rerun_if_env_changed!(CSV_LOCALE_COLUMNS_VAR);
+    cargo_emit::rerun_if_changed!(translations::LOCALES_PATH);
+
+    // Fails the build on colliding keys instead of letting `rust-i18n` silently overwrite them
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let backends = translations::Backends {
+        fluent: env::var_os("CARGO_FEATURE_FLUENT").is_some(),
+        gettext: env::var_os("CARGO_FEATURE_GETTEXT").is_some(),
+        csv: env::var_os("CARGO_FEATURE_CSV").is_some().then(|| {
+            csv::Columns::new(
+                env::var(CSV_KEY_COLUMN_VAR).ok().as_deref(),
+                env::var(CSV_LOCALE_COLUMNS_VAR).ok().as_deref(),
+            )
+        }),
+    };
+    let mut roots = vec![Path::new(&manifest_dir).join(translations::LOCALES_PATH)];
+    if let Some(paths) = env::var_os(LOCALE_ROOTS_VAR) {
+        roots.extend(env::split_paths(&paths).map(|path| Path::new(&manifest_dir).join(path)));
+    }
+    let translations =
+        translations::load_roots(&roots, &backends).unwrap_or_else(|err| panic!("{}", err));
+
+    if let Ok(reference) = env::var(REFERENCE_LOCALE_VAR) {
+        let report = translations::consistency_report(&translations, &reference);
+        let deny = env::var(DENY_MISSING_KEYS_VAR).is_ok_and(|v| v == "1" || v == "true");
+        if deny && !report.is_empty() {
+            panic!("Inconsistent translation keys:\n  {}", report.join("\n  "));
+        }
+        for line in report {
+            cargo_emit::warning!("{}", line);
+        }
+    }
 
     let out_dir = env::var_os("OUT_DIR").unwrap();
 
+    fs::write(
+        Path::new(&out_dir).join(KEYS_FILE_NAME),
+        translations::write_key_validation(&translations),
+    )
+    .unwrap();
+    let (keys_module, collisions) = translations::write_keys_module(&translations);
+    fs::write(Path::new(&out_dir).join(KEYS_MODULE_FILE_NAME), keys_module).unwrap();
+    for collision in collisions {
+        cargo_emit::warning!("Skipped a key of the keys module, {}", collision);
+    }
+    fs::write(
+        Path::new(&out_dir).join(PLACEHOLDERS_FILE_NAME),
+        translations::write_placeholder_table(&translations),
+    )
+    .unwrap();
+    for mismatch in translations::placeholder_mismatches(&translations) {
+        cargo_emit::warning!("Placeholders differ between locales, {}", mismatch);
+    }
+    // Only the locales are embedded with the `external_translations` feature, their files are loaded at runtime
+    let embedded = if env::var_os("CARGO_FEATURE_EXTERNAL_TRANSLATIONS").is_some() {
+        translations
+            .keys()
+            .map(|locale| (locale.clone(), Default::default()))
+            .collect()
+    } else {
+        translations
+    };
+    fs::write(
+        Path::new(&out_dir).join(ROOTS_FILE_NAME),
+        translations::write_root_table(&embedded),
+    )
+    .unwrap();
+    fs::write(
+        Path::new(&out_dir).join(BACKENDS_FILE_NAME),
+        translations::write_backend_table(&embedded),
+    )
+    .unwrap();
+    fs::write(
+        Path::new(&out_dir).join(LOCALE_KEYS_FILE_NAME),
+        translations::write_locale_keys(&embedded),
+    )
+    .unwrap();
+    fs::write(
+        Path::new(&out_dir).join(LISTS_FILE_NAME),
+        translations::write_list_table(&embedded),
+    )
+    .unwrap();
+    fs::write(
+        Path::new(&out_dir).join(GLYPHS_FILE_NAME),
+        translations::write_glyph_table(&embedded),
+    )
+    .unwrap();
+
     let mut files = Vec::new();
+    let mut localized_assets = Vec::new();
+    let mut audio_folders: Vec<(String, Vec<String>)> = Vec::new();
+    let mut scales: Vec<(PathBuf, Scales, Spacings)> = Vec::new();
 
     // Check if env variable is set for the assets folder
     if let Some(dir) = env::var(ASSET_PATH_VAR)
@@ -70,6 +199,7 @@ fn main() {
         // cargo_emit::warning!("Asset folder found: {}", dir.to_string_lossy());
 
         let building_for_wasm = std::env::var("CARGO_CFG_TARGET_ARCH") == Ok("wasm32".to_string());
+        let fonts = env::var_os("CARGO_FEATURE_FONTS").is_some();
 
         visit_dirs(&dir)
             .iter()
@@ -81,8 +211,19 @@ fn main() {
                     string_path = string_path.replace(std::path::MAIN_SEPARATOR, "/");
                 }
                 cargo_emit::rerun_if_changed!(full_path.to_string_lossy());
-                if let Some(ext) = full_path.extension().and_then(|e| e.to_str()) {
+                if path.file_name().and_then(|f| f.to_str()) == Some(SCALES_FILE_NAME) {
+                    let (family_scales, spacings) = parse_scales(full_path);
+                    scales.push((
+                        PathBuf::from(string_path).parent().unwrap().into(),
+                        family_scales,
+                        spacings,
+                    ));
+                } else if let Some(ext) = full_path.extension().and_then(|e| e.to_str()) {
                     if ALLOWED_EXTENSIONS.contains(&ext) {
+                        // The dynamic fonts are compiled out without the `fonts` feature
+                        if !fonts {
+                            return;
+                        }
                         // Extract filename without extension
                         let locale = path.file_stem().unwrap().to_string_lossy().into_owned();
 
@@ -98,13 +239,26 @@ fn main() {
                             .to_string_lossy()
                             .into_owned();
 
+                        let variable = weight_axis(full_path);
                         files.push(FontAsset {
-                            is_fallback: locale == "fallback",
+                            // Style variants such as `fallback.bold.ttf` are fallbacks too
+                            is_fallback: locale.split('.').next() == Some("fallback"),
                             path: PathBuf::from(string_path),
                             family,
                             locale,
                             ext,
+                            variable,
                         });
+                    } else if AUDIO_EXTENSIONS.contains(&ext) && is_audio_clip(path) {
+                        let folder = path.parent().unwrap().to_string_lossy().replace('\\', "/");
+                        let file_name = path.file_name().unwrap().to_string_lossy().into_owned();
+                        match audio_folders.iter_mut().find(|(f, _)| *f == folder) {
+                            Some((_, clips)) => clips.push(file_name),
+                            None => audio_folders.push((folder, vec![file_name])),
+                        }
+                    } else if is_localized(path) {
+                        // Always `/` separated, like the paths passed to the asset server
+                        localized_assets.push(string_path.replace('\\', "/"));
                     }
                 }
             });
@@ -118,24 +272,89 @@ fn main() {
         // panic!("No asset folder found");
     }
 
+    localized_assets.sort();
+    fs::write(
+        Path::new(&out_dir).join(LOCALIZED_ASSETS_FILE_NAME),
+        format!(
+            r#"/// Assets with a locale suffix, `images/signpost.fr.png`, sorted by path
+pub(crate) static LOCALIZED_ASSETS: &[&str] = &{:?};
+"#,
+            localized_assets
+        ),
+    )
+    .unwrap();
+
+    audio_folders.sort();
+    for (_, clips) in audio_folders.iter_mut() {
+        clips.sort();
+    }
+    fs::write(
+        Path::new(&out_dir).join(AUDIO_FILE_NAME),
+        format!(
+            r#"/// Folders of the localized sounds and their `{{locale}}.{{ext}}` and `fallback.{{ext}}` clips, sorted by folder
+pub(crate) static AUDIO_FOLDERS: &[(&str, &[&str])] = &[{}];
+"#,
+            audio_folders
+                .iter()
+                .map(|(folder, clips)| format!("({:?}, &{:?})", folder, clips))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    )
+    .unwrap();
+
     let mut families: Vec<FontFamily> = Vec::new();
     for asset in files.iter() {
-        if let Some(family) = families.iter_mut().find(|f| f.folder == asset.family) {
-            family
-                .locales
-                .push(format!("{}.{}", asset.locale, asset.ext));
+        let family = if let Some(index) = families.iter().position(|f| f.folder == asset.family) {
+            &mut families[index]
         } else {
             families.push(FontFamily {
                 path: asset.path.parent().unwrap().to_string_lossy().to_string(),
                 folder: asset.family.clone(),
-                locales: if asset.is_fallback {
-                    vec![]
-                } else {
-                    vec![format!("{}.{}", asset.locale, asset.ext)]
-                },
+                locales: vec![],
+                fallbacks: vec![],
+                variable: vec![],
+                scales: scales
+                    .iter()
+                    .find(|(path, _, _)| Some(path.as_path()) == asset.path.parent())
+                    .map(|(_, scales, _)| scales.clone())
+                    .unwrap_or_default(),
+                spacings: scales
+                    .iter()
+                    .find(|(path, _, _)| Some(path.as_path()) == asset.path.parent())
+                    .map(|(_, _, spacings)| spacings.clone())
+                    .unwrap_or_default(),
             });
+            families.last_mut().unwrap()
+        };
+        let file_name = format!("{}.{}", asset.locale, asset.ext);
+        // Files are matched by their stem, so `ja.ttf` and `ja.otf` would both be the `ja` font
+        if let Some(other) = family
+            .locales
+            .iter()
+            .chain(family.fallbacks.iter())
+            .find(|other| other.rsplit_once('.').map(|(stem, _)| stem) == Some(&asset.locale))
+        {
+            panic!(
+                "Font files {} and {} of the {} family are both the {} font, keep only one of them",
+                other, file_name, family.folder, asset.locale
+            );
+        }
+        if asset.is_fallback {
+            family.fallbacks.push(file_name);
+        } else {
+            if let Some((min, max)) = asset.variable {
+                family.variable.push((file_name.clone(), min, max));
+            }
+            family.locales.push(file_name);
         }
     }
+    // `fallback.*` first, then the named fallbacks of the chain by name
+    for family in families.iter_mut() {
+        family
+            .fallbacks
+            .sort_by_key(|font| (font.matches('.').count() > 1, font.clone()));
+    }
     let mut marker_file = File::create(Path::new(&out_dir).join(OUTPUT_FILE_NAME)).unwrap();
 
     marker_file
@@ -146,10 +365,16 @@ pub(crate) struct FontFamily {{
     pub path: &'static str,
     pub family: &'static str,
     pub locales: &'static [&'static str],
+    pub fallbacks: &'static [&'static str],
+    pub scales: &'static [(&'static str, f32)],
+    /// Line height and letter spacing of the locales
+    pub spacings: &'static [(&'static str, Option<f32>, Option<f32>)],
+    /// Variable locale fonts and the range of their `wght` axis
+    pub variable: &'static [(&'static str, u16, u16)],
 }}
 
 {}
-pub(crate) const FONT_FAMILIES: &'static [FontFamily] = &[{}];
+pub(crate) const FONT_FAMILIES: &[FontFamily] = &[{}];
 "#,
                 families
                     .iter()
@@ -173,12 +398,18 @@ struct FontAsset {
     family: String,
     locale: String,
     is_fallback: bool,
+    /// Range of the `wght` axis of a variable font
+    variable: Option<(u16, u16)>,
 }
 
 struct FontFamily {
     path: String,
     folder: String,
     locales: Vec<String>,
+    fallbacks: Vec<String>,
+    scales: Vec<(String, f32)>,
+    spacings: Spacings,
+    variable: Vec<(String, u16, u16)>,
 }
 
 impl FontFamily {
@@ -188,17 +419,25 @@ impl FontFamily {
     path: {:?},
     family: "{}",
     locales: &{:?},
+    fallbacks: &{:?},
+    scales: &{:?},
+    spacings: &{:?},
+    variable: &{:?},
 }};
 "#,
             self.snake_case().to_uppercase(),
             self.path,
             self.folder,
-            self.locales
+            self.locales,
+            self.fallbacks,
+            self.scales,
+            self.spacings,
+            self.variable
         )
     }
 
     fn push_const(&self) -> String {
-        format!("{}", self.snake_case().to_uppercase())
+        self.snake_case().to_uppercase()
     }
 
     fn snake_case(&self) -> String {
@@ -216,6 +455,101 @@ impl FontFamily {
     }
 }
 
+/// Range of the `wght` axis of a variable font, `None` for a static font
+fn weight_axis(path: &Path) -> Option<(u16, u16)> {
+    let data = fs::read(path).ok()?;
+    let face = ttf_parser::Face::parse(&data, 0).ok()?;
+    let axis = face
+        .variation_axes()
+        .into_iter()
+        .find(|axis| axis.tag == ttf_parser::Tag::from_bytes(b"wght"))?;
+    Some((axis.min_value as u16, axis.max_value as u16))
+}
+
+/// Whether the stem of an asset ends with a locale, `signpost.fr.png` or `signpost.pt-BR.png`
+fn is_localized(path: &Path) -> bool {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(|stem| stem.rsplit_once('.'))
+        .is_some_and(|(_, locale)| is_locale(locale))
+}
+
+/// Whether the stem of an audio file is a locale or `fallback`, `fr.ogg`
+fn is_audio_clip(path: &Path) -> bool {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .is_some_and(|stem| stem == "fallback" || is_locale(stem))
+}
+
+fn is_locale(locale: &str) -> bool {
+    let mut subtags = locale.split(['-', '_']);
+    let language = subtags.next().unwrap_or_default();
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && subtags.all(|subtag| {
+            (2..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
+
+/// `(locale, multiplier)` of the locales of a [SCALES_FILE_NAME] file
+type Scales = Vec<(String, f32)>;
+
+/// `(locale, line height, letter spacing)` of the locales of a [SCALES_FILE_NAME] file
+type Spacings = Vec<(String, Option<f32>, Option<f32>)>;
+
+/// Parses the `locale = multiplier` pairs of a [SCALES_FILE_NAME] file,
+/// along with the `locale = { scale, line_height, letter_spacing }` tables
+fn parse_scales(path: &Path) -> (Scales, Spacings) {
+    let content = fs::read_to_string(path).unwrap();
+    let table: toml::Table = toml::from_str(&content)
+        .unwrap_or_else(|err| panic!("Could not parse {}: {}", path.display(), err));
+    let number = |locale: &str, name: &str, value: &toml::Value| {
+        value
+            .as_float()
+            .or_else(|| value.as_integer().map(|v| v as f64))
+            .unwrap_or_else(|| {
+                panic!(
+                    "Font {} of {} in {} must be a number",
+                    name,
+                    locale,
+                    path.display()
+                )
+            }) as f32
+    };
+    let mut scales = vec![];
+    let mut spacings = vec![];
+    for (locale, value) in table {
+        let Some(metrics) = value.as_table() else {
+            scales.push((locale.clone(), number(&locale, "scale", &value)));
+            continue;
+        };
+        if let Some(name) = metrics
+            .keys()
+            .find(|name| !["scale", "line_height", "letter_spacing"].contains(&name.as_str()))
+        {
+            panic!(
+                "Unknown font metric {} of {} in {}, expected scale, line_height or letter_spacing",
+                name,
+                locale,
+                path.display()
+            );
+        }
+        if let Some(scale) = metrics.get("scale") {
+            scales.push((locale.clone(), number(&locale, "scale", scale)));
+        }
+        let line_height = metrics
+            .get("line_height")
+            .map(|value| number(&locale, "line height", value));
+        let letter_spacing = metrics
+            .get("letter_spacing")
+            .map(|value| number(&locale, "letter spacing", value));
+        if line_height.is_some() || letter_spacing.is_some() {
+            spacings.push((locale, line_height, letter_spacing));
+        }
+    }
+    (scales, spacings)
+}
+
 fn visit_dirs(dir: &Path) -> Vec<PathBuf> {
     let mut collected = vec![];
     if dir.is_dir() {
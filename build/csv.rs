@@ -0,0 +1,248 @@
+//! Parser of the CSV (`.csv`) locale files exported from spreadsheets, enabled by the `csv` feature
+//!
+//! The first record of the file is its header, with a key column and one column per locale,
+//! so a single file holds the translations of every locale:
+//!
+//! ```csv
+//! key,en,fr,notes
+//! menu.start,Start,Commencer,Main menu button
+//! menu.quit,Quit,,
+//! ```
+//!
+//! The locale columns are the other columns named after a locale unless they're listed explicitly,
+//! the `notes` column above is skipped. Empty cells are missing translations, which resolve to the fallback locales.
+//! Fields are quoted per RFC 4180, a quoted field can contain commas, line breaks and `""` escaped quotes
+
+/// Name of the key column when it isn't configured
+pub const DEFAULT_KEY_COLUMN: &str = "key";
+
+/// Columns of the CSV files that the keys and translations are read from
+#[derive(Debug, Clone)]
+pub struct Columns {
+    key: String,
+    /// Locale columns, every column named after a locale when `None`
+    locales: Option<Vec<String>>,
+}
+
+impl Columns {
+    /// Columns from a key column name, [DEFAULT_KEY_COLUMN] when `None`,
+    /// and a comma-separated list of locale columns, `en,fr,pt-BR`
+    pub fn new(key: Option<&str>, locales: Option<&str>) -> Self {
+        Self {
+            key: key
+                .map(str::trim)
+                .filter(|key| !key.is_empty())
+                .unwrap_or(DEFAULT_KEY_COLUMN)
+                .to_string(),
+            locales: locales.map(|locales| {
+                locales
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|locale| !locale.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            }),
+        }
+    }
+}
+
+/// Parses a CSV file into its `(locale, key, value)` translations, skipping the empty cells and the rows without a key
+pub fn parse(content: &str, columns: &Columns) -> Result<Vec<(String, String, String)>, String> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut records = records(content)?.into_iter();
+    let Some((_, header)) = records.next() else {
+        return Ok(vec![]);
+    };
+    let key_index = header
+        .iter()
+        .position(|name| name.trim() == columns.key)
+        .ok_or_else(|| format!("The header has no {} key column", columns.key))?;
+    let locale_indices: Vec<(usize, &str)> = match columns.locales.as_ref() {
+        Some(locales) => locales
+            .iter()
+            .map(|locale| {
+                header
+                    .iter()
+                    .position(|name| name.trim() == locale)
+                    .map(|index| (index, locale.as_str()))
+                    .ok_or_else(|| format!("The header has no {} locale column", locale))
+            })
+            .collect::<Result<_, _>>()?,
+        None => header
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (index, name.trim()))
+            .filter(|(index, name)| *index != key_index && super::locale_path::is_locale_code(name))
+            .collect(),
+    };
+
+    let mut translations = vec![];
+    for (line, record) in records {
+        if record.len() > header.len() {
+            return Err(format!(
+                "Expected at most {} fields on line {}, found {}",
+                header.len(),
+                line,
+                record.len()
+            ));
+        }
+        let key = record
+            .get(key_index)
+            .map(|key| key.trim())
+            .unwrap_or_default();
+        if key.is_empty() {
+            continue;
+        }
+        for (index, locale) in locale_indices.iter() {
+            if let Some(value) = record.get(*index).filter(|value| !value.is_empty()) {
+                translations.push((locale.to_string(), key.to_string(), value.clone()));
+            }
+        }
+    }
+    Ok(translations)
+}
+
+/// Splits the content into its records of fields along with the line each record starts on, skipping the blank lines
+fn records(content: &str) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut start = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                c => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => quoted = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.len() > 1 || !record[0].is_empty() {
+                    records.push((start, std::mem::take(&mut record)));
+                }
+                record.clear();
+                line += 1;
+                start = line;
+            }
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(format!("Unterminated quoted field on line {}", start));
+    }
+    if !record.is_empty() || !field.is_empty() {
+        record.push(field);
+        records.push((start, record));
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_default(content: &str) -> Result<Vec<(String, String, String)>, String> {
+        parse(content, &Columns::new(None, None))
+    }
+
+    fn translation(locale: &str, key: &str, value: &str) -> (String, String, String) {
+        (locale.to_string(), key.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn reads_the_locale_columns_and_skips_the_others() {
+        let translations = parse_default("key,en,fr,notes\nhello,Hello,Bonjour,Greeting\n");
+        assert_eq!(
+            translations.unwrap(),
+            [
+                translation("en", "hello", "Hello"),
+                translation("fr", "hello", "Bonjour"),
+            ]
+        );
+    }
+
+    #[test]
+    fn quoted_fields_keep_their_commas_newlines_and_escaped_quotes() {
+        let content = "key,en\ngreeting,\"Hello, {name}\"\nlines,\"First\nSecond\"\nquote,\"She said \"\"hi\"\"\"\n";
+        assert_eq!(
+            parse_default(content).unwrap(),
+            [
+                translation("en", "greeting", "Hello, {name}"),
+                translation("en", "lines", "First\nSecond"),
+                translation("en", "quote", "She said \"hi\""),
+            ]
+        );
+    }
+
+    #[test]
+    fn crlf_line_endings_and_bom_are_stripped() {
+        let content = "\u{feff}key,en\r\nhello,Hello\r\nbye,\"Bye\r\nnow\"\r\n";
+        assert_eq!(
+            parse_default(content).unwrap(),
+            [
+                translation("en", "hello", "Hello"),
+                translation("en", "bye", "Bye\r\nnow"),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_cells_blank_lines_and_rows_without_key_are_skipped() {
+        let content = "key,en,fr\nhello,Hello,\n\n,Orphan,Orphelin\nbye,Bye,Au revoir";
+        assert_eq!(
+            parse_default(content).unwrap(),
+            [
+                translation("en", "hello", "Hello"),
+                translation("en", "bye", "Bye"),
+                translation("fr", "bye", "Au revoir"),
+            ]
+        );
+    }
+
+    #[test]
+    fn too_many_fields_is_an_error() {
+        let err = parse_default("key,en\nhello,Hello,extra\n").unwrap_err();
+        assert_eq!(err, "Expected at most 2 fields on line 2, found 3");
+    }
+
+    #[test]
+    fn unterminated_quotes_are_an_error() {
+        let err = parse_default("key,en\nhello,Hello\nbye,\"Bye\n").unwrap_err();
+        assert_eq!(err, "Unterminated quoted field on line 3");
+    }
+
+    #[test]
+    fn configured_columns_are_required() {
+        let columns = Columns::new(Some("id"), Some("fr"));
+        assert_eq!(
+            parse("id,en,fr\nhello,Hello,Bonjour\n", &columns).unwrap(),
+            [translation("fr", "hello", "Bonjour")]
+        );
+        assert_eq!(
+            parse("key,en\n", &columns).unwrap_err(),
+            "The header has no id key column"
+        );
+        assert_eq!(
+            parse("id,en\n", &columns).unwrap_err(),
+            "The header has no fr locale column"
+        );
+    }
+}
@@ -0,0 +1,538 @@
+//! Minimal parser of the Fluent (`.ftl`) locale files, enabled by the `fluent` feature
+//!
+//! Messages and their attributes become flat keys, `login.placeholder` for the `.placeholder` attribute of `login`,
+//! and `{ $arg }` placeables become the `{arg}` placeholders of the other formats.
+//!
+//! A message made of a single select expression is split into one key per variant,
+//! `emails.one` and `emails.other`, which is how the plural forms and context variants are looked up.
+//! The default variant is also used for the message key itself.
+//!
+//! Term and message references are inlined when they're defined in the same file
+
+use std::collections::HashMap;
+
+/// Element of a parsed pattern
+#[derive(Debug, Clone)]
+enum Element {
+    Text(String),
+    /// Reference to a message, `other` or `other.attr`, or a term, `-brand`
+    Reference(String),
+    Select(Vec<Variant>),
+}
+
+#[derive(Debug, Clone)]
+struct Variant {
+    key: String,
+    default: bool,
+    pattern: Vec<Element>,
+}
+
+/// Message or term with its attributes, `None` for the message key of an attributes-only message
+struct Entry {
+    id: String,
+    value: Option<Vec<Element>>,
+    attributes: Vec<(String, Vec<Element>)>,
+}
+
+/// Parses a Fluent file into its `(key, value)` translations, terms are only used by the references
+pub fn parse(content: &str) -> Result<Vec<(String, String)>, String> {
+    let entries = parse_entries(content)?;
+    let mut references: HashMap<String, &Vec<Element>> = HashMap::new();
+    for entry in entries.iter() {
+        if let Some(value) = entry.value.as_ref() {
+            references.insert(entry.id.clone(), value);
+        }
+        for (name, value) in entry.attributes.iter() {
+            references.insert(format!("{}.{}", entry.id, name), value);
+        }
+    }
+
+    let mut translations = vec![];
+    for entry in entries.iter().filter(|entry| !entry.id.starts_with('-')) {
+        let patterns = entry
+            .value
+            .iter()
+            .map(|value| (entry.id.clone(), value))
+            .chain(
+                entry
+                    .attributes
+                    .iter()
+                    .map(|(name, value)| (format!("{}.{}", entry.id, name), value)),
+            );
+        for (key, pattern) in patterns {
+            for (variant, value) in expand(pattern, &references, 0)
+                .map_err(|err| format!("Could not expand `{}`: {}", key, err))?
+            {
+                match variant {
+                    Some(variant) => translations.push((format!("{}.{}", key, variant), value)),
+                    None => translations.push((key.clone(), value)),
+                }
+            }
+        }
+    }
+    Ok(translations)
+}
+
+/// Lines of an entry that's being parsed, its id followed by the lines of its value and of each attribute
+type RawEntry<'a> = (String, Vec<(Option<String>, Vec<&'a str>)>);
+
+/// Splits the file into its entries, the indented lines following an entry are part of its value or attributes
+fn parse_entries(content: &str) -> Result<Vec<Entry>, String> {
+    let mut entries = vec![];
+    let mut current: Option<RawEntry> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let is_indented = line.starts_with([' ', '\t']);
+        if line.trim().is_empty() {
+            if let Some((_, parts)) = current.as_mut() {
+                parts.last_mut().unwrap().1.push("");
+            }
+            continue;
+        }
+        if !is_indented {
+            finish_entry(current.take(), &mut entries)?;
+            if line.starts_with('#') {
+                continue;
+            }
+            let (id, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("Expected `=` on line {}: {}", index + 1, line))?;
+            let id = id.trim();
+            if !is_identifier(id.strip_prefix('-').unwrap_or(id)) {
+                return Err(format!("Invalid identifier on line {}: {}", index + 1, id));
+            }
+            current = Some((id.to_string(), vec![(None, vec![value.trim_start()])]));
+            continue;
+        }
+        let Some((_, parts)) = current.as_mut() else {
+            return Err(format!("Unexpected indentation on line {}", index + 1));
+        };
+        let trimmed = line.trim_start();
+        let attribute = trimmed
+            .strip_prefix('.')
+            .and_then(|rest| rest.split_once('='))
+            .filter(|(name, _)| is_identifier(name.trim()));
+        match attribute {
+            Some((name, value)) => {
+                parts.push((Some(name.trim().to_string()), vec![value.trim_start()]));
+            }
+            None => parts.last_mut().unwrap().1.push(line),
+        }
+    }
+    finish_entry(current.take(), &mut entries)?;
+    Ok(entries)
+}
+
+fn finish_entry(current: Option<RawEntry>, entries: &mut Vec<Entry>) -> Result<(), String> {
+    let Some((id, parts)) = current else {
+        return Ok(());
+    };
+    let mut entry = Entry {
+        id: id.clone(),
+        value: None,
+        attributes: vec![],
+    };
+    for (attribute, lines) in parts {
+        let text = dedent(&lines);
+        if text.is_empty() {
+            continue;
+        }
+        let pattern = Parser::new(&text)
+            .parse_pattern(false)
+            .map_err(|err| format!("Could not parse `{}`: {}", id, err))?;
+        match attribute {
+            Some(name) => entry.attributes.push((name, pattern)),
+            None => entry.value = Some(pattern),
+        }
+    }
+    entries.push(entry);
+    Ok(())
+}
+
+/// Joins the first line with the continuation lines, removing their common indentation
+fn dedent(lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut text = lines[0].to_string();
+    for line in lines.iter().skip(1) {
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(line.get(indent..).unwrap_or_default());
+    }
+    text.trim_end().to_string()
+}
+
+fn is_identifier(id: &str) -> bool {
+    let mut chars = id.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Expands a pattern into its `(variant, value)` translations, `None` being the message key itself
+fn expand(
+    pattern: &[Element],
+    references: &HashMap<String, &Vec<Element>>,
+    depth: usize,
+) -> Result<Vec<(Option<String>, String)>, String> {
+    if depth > 16 {
+        return Err("References are nested too deeply".to_string());
+    }
+    let mut prefix = String::new();
+    let mut select: Option<(&Vec<Variant>, String)> = None;
+    for element in pattern {
+        let text = match element {
+            Element::Text(text) => text.clone(),
+            Element::Reference(id) => {
+                let referenced = references
+                    .get(id)
+                    .ok_or_else(|| format!("Unknown reference `{}`", id))?;
+                default_value(expand(referenced, references, depth + 1)?)
+            }
+            Element::Select(variants) => {
+                if select.is_some() {
+                    return Err("Only one select expression per message is supported".to_string());
+                }
+                select = Some((variants, String::new()));
+                continue;
+            }
+        };
+        match select.as_mut() {
+            Some((_, suffix)) => suffix.push_str(&text),
+            None => prefix.push_str(&text),
+        }
+    }
+
+    let Some((variants, suffix)) = select else {
+        return Ok(vec![(None, prefix)]);
+    };
+    let mut expanded = vec![];
+    for variant in variants {
+        let values = expand(&variant.pattern, references, depth + 1)?;
+        if values.len() > 1 {
+            return Err("Nested select expressions are not supported".to_string());
+        }
+        let value = format!("{}{}{}", prefix, default_value(values), suffix);
+        if variant.default {
+            expanded.push((None, value.clone()));
+        }
+        expanded.push((Some(variant.key.clone()), value));
+    }
+    Ok(expanded)
+}
+
+fn default_value(values: Vec<(Option<String>, String)>) -> String {
+    values
+        .into_iter()
+        .find(|(variant, _)| variant.is_none())
+        .map(|(_, value)| value)
+        .unwrap_or_default()
+}
+
+/// Parser of the patterns of a message, its text and `{ }` placeables
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn new(text: &str) -> Self {
+        Self {
+            chars: text.chars().collect(),
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            c => Err(format!("Expected `{}`, found {:?}", expected, c)),
+        }
+    }
+
+    /// Whether the rest of the line is blank and the next line starts a variant
+    fn at_variant(&self) -> bool {
+        let rest = self.chars[self.position..]
+            .iter()
+            .skip_while(|c| c.is_whitespace());
+        let mut rest = rest.peekable();
+        matches!(rest.peek(), Some('[') | Some('*'))
+    }
+
+    /// Parses text and placeables, until the end of a variant when in a select expression
+    fn parse_pattern(&mut self, in_select: bool) -> Result<Vec<Element>, String> {
+        let mut elements = vec![];
+        let mut text = String::new();
+        if in_select {
+            // The value of a variant can start on the next line
+            if self.at_variant() {
+                return Ok(elements);
+            }
+            self.skip_whitespace();
+        }
+        while let Some(c) = self.peek() {
+            if in_select && (c == '}' || (c == '\n' && self.at_variant())) {
+                break;
+            }
+            if c == '{' {
+                if !text.is_empty() {
+                    elements.push(Element::Text(std::mem::take(&mut text)));
+                }
+                elements.push(self.parse_placeable()?);
+                continue;
+            }
+            text.push(c);
+            self.position += 1;
+        }
+        if in_select {
+            text = text.trim_end().to_string();
+        }
+        if !text.is_empty() {
+            elements.push(Element::Text(text));
+        }
+        Ok(elements)
+    }
+
+    fn parse_placeable(&mut self) -> Result<Element, String> {
+        self.expect('{')?;
+        self.skip_whitespace();
+        let expression = self.parse_expression()?;
+        self.skip_whitespace();
+        if self.chars[self.position..].starts_with(&['-', '>']) {
+            self.position += 2;
+            let variants = self.parse_variants()?;
+            return Ok(Element::Select(variants));
+        }
+        self.expect('}')?;
+        Ok(expression)
+    }
+
+    fn parse_expression(&mut self) -> Result<Element, String> {
+        match self.peek() {
+            Some('"') => {
+                self.position += 1;
+                let mut literal = String::new();
+                loop {
+                    match self.peek() {
+                        Some('"') => break,
+                        Some('\\') => {
+                            self.position += 1;
+                            let escaped = self.peek().ok_or("Unterminated string literal")?;
+                            literal.push(escaped);
+                        }
+                        Some(c) => literal.push(c),
+                        None => return Err("Unterminated string literal".to_string()),
+                    }
+                    self.position += 1;
+                }
+                self.position += 1;
+                Ok(Element::Text(literal))
+            }
+            Some('$') => {
+                self.position += 1;
+                Ok(Element::Text(format!("{{{}}}", self.parse_identifier())))
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' => {
+                let is_term = c == '-'
+                    && self
+                        .chars
+                        .get(self.position + 1)
+                        .is_some_and(char::is_ascii_alphabetic);
+                if is_term {
+                    self.position += 1;
+                    return Ok(Element::Reference(format!(
+                        "-{}",
+                        self.parse_reference_id()
+                    )));
+                }
+                let mut number = String::from(c);
+                self.position += 1;
+                while let Some(c) = self.peek().filter(|c| c.is_ascii_digit() || *c == '.') {
+                    number.push(c);
+                    self.position += 1;
+                }
+                Ok(Element::Text(number))
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                let id = self.parse_reference_id();
+                if self.peek() != Some('(') {
+                    return Ok(Element::Reference(id));
+                }
+                // Functions like `NUMBER($count)` are replaced by their first variable
+                let start = self.position;
+                let end = self.chars[start..]
+                    .iter()
+                    .position(|c| *c == ')')
+                    .ok_or_else(|| format!("Unterminated call of {}", id))?;
+                let arguments: String = self.chars[start + 1..start + end].iter().collect();
+                self.position = start + end + 1;
+                let variable = arguments
+                    .split(',')
+                    .find_map(|argument| argument.trim().strip_prefix('$'))
+                    .ok_or_else(|| format!("{} has no variable argument", id))?;
+                Ok(Element::Text(format!("{{{}}}", variable.trim())))
+            }
+            c => Err(format!("Unexpected {:?} in placeable", c)),
+        }
+    }
+
+    fn parse_identifier(&mut self) -> String {
+        let mut id = String::new();
+        while let Some(c) = self
+            .peek()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-')
+        {
+            id.push(c);
+            self.position += 1;
+        }
+        id
+    }
+
+    /// Identifier of a message or term, with its optional attribute
+    fn parse_reference_id(&mut self) -> String {
+        let mut id = self.parse_identifier();
+        if self.peek() == Some('.') {
+            self.position += 1;
+            id.push('.');
+            id.push_str(&self.parse_identifier());
+        }
+        id
+    }
+
+    fn parse_variants(&mut self) -> Result<Vec<Variant>, String> {
+        let mut variants = vec![];
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => {
+                    self.position += 1;
+                    break;
+                }
+                None => return Err("Unterminated select expression".to_string()),
+                _ => {}
+            }
+            let default = self.peek() == Some('*');
+            if default {
+                self.position += 1;
+            }
+            self.expect('[')?;
+            let start = self.position;
+            let end = self.chars[start..]
+                .iter()
+                .position(|c| *c == ']')
+                .ok_or("Unterminated variant key")?;
+            let key: String = self.chars[start..start + end].iter().collect();
+            self.position = start + end + 1;
+            while self.peek().is_some_and(|c| c == ' ' || c == '\t') {
+                self.position += 1;
+            }
+            let pattern = self.parse_pattern(true)?;
+            variants.push(Variant {
+                key: key.trim().to_string(),
+                default,
+                pattern,
+            });
+        }
+        if !variants.iter().any(|variant| variant.default) {
+            return Err("Select expression has no default variant".to_string());
+        }
+        Ok(variants)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sorted(content: &str) -> Vec<(String, String)> {
+        let mut translations = parse(content).unwrap();
+        translations.sort();
+        translations
+    }
+
+    fn translation(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn messages_attributes_and_placeables() {
+        let content = "hello = Hello, { $name }!\nlogin =\n    .placeholder = Email\n";
+        assert_eq!(
+            parse_sorted(content),
+            [
+                translation("hello", "Hello, {name}!"),
+                translation("login.placeholder", "Email"),
+            ]
+        );
+    }
+
+    #[test]
+    fn plural_select_is_split_into_one_key_per_category() {
+        let content = "emails = You have { $count ->\n    [one] one email\n   *[other] { $count } emails\n }.\n";
+        assert_eq!(
+            parse_sorted(content),
+            [
+                translation("emails", "You have {count} emails."),
+                translation("emails.one", "You have one email."),
+                translation("emails.other", "You have {count} emails."),
+            ]
+        );
+    }
+
+    #[test]
+    fn context_select_is_split_into_one_key_per_variant() {
+        let content = "friend = { $gender ->\n    [male] his friend\n    [female] her friend\n   *[other] their friend\n }\n";
+        assert_eq!(
+            parse_sorted(content),
+            [
+                translation("friend", "their friend"),
+                translation("friend.female", "her friend"),
+                translation("friend.male", "his friend"),
+                translation("friend.other", "their friend"),
+            ]
+        );
+    }
+
+    #[test]
+    fn terms_and_messages_are_inlined() {
+        let content = "-brand = Bevy\nabout = About { -brand }\ntitle = { about }!\n";
+        assert_eq!(
+            parse_sorted(content),
+            [
+                translation("about", "About Bevy"),
+                translation("title", "About Bevy!"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unsupported_selects_are_errors() {
+        let nested = "a = { $x ->\n   *[one] { $y ->\n       *[two] b\n    }\n }\n";
+        assert!(parse(nested).unwrap_err().contains("Nested select"));
+        let no_default = "a = { $x ->\n    [one] b\n }\n";
+        assert!(parse(no_default)
+            .unwrap_err()
+            .contains("no default variant"));
+        assert!(parse("a = { missing }\n")
+            .unwrap_err()
+            .contains("Unknown reference"));
+    }
+}
@@ -0,0 +1,428 @@
+//! Parser of the gettext source (`.po`) and compiled (`.mo`) locale files, enabled by the `gettext` feature
+//!
+//! The `msgid` is the translation key, and the `msgctxt` selects a context variant of it, `msgid.msgctxt`.
+//! The `msgstr[n]` forms of a plural message become the plural variants of the key, `msgid.one` and `msgid.other`,
+//! in the order of the CLDR categories for the number of forms of the `Plural-Forms` header:
+//!
+//! - 1: `other`
+//! - 2: `one`, `other`
+//! - 3: `one`, `few`, `other`
+//! - 4: `one`, `two`, `few`, `other`
+//! - 5: `one`, `two`, `few`, `many`, `other`
+//! - 6: `zero`, `one`, `two`, `few`, `many`, `other`
+//!
+//! The last form is always `other`, which is also used for the CLDR categories that gettext doesn't distinguish.
+//! Python format placeholders, `%(name)s`, become the `{name}` placeholders of the other formats.
+//! Fuzzy and untranslated messages are skipped, like `msgfmt` does
+
+/// A message of a gettext file
+#[derive(Default)]
+struct Message {
+    context: Option<String>,
+    id: String,
+    id_plural: Option<String>,
+    translations: Vec<String>,
+    fuzzy: bool,
+}
+
+/// Field of the message that the continuation lines of a `.po` file are appended to
+#[derive(Clone, Copy)]
+enum Field {
+    Context,
+    Id,
+    IdPlural,
+    Translation(usize),
+}
+
+/// Parses a `.po` file into its `(key, value)` translations
+pub fn parse_po(content: &str) -> Result<Vec<(String, String)>, String> {
+    let mut messages = vec![];
+    let mut message = Message::default();
+    let mut field = None;
+    let mut fuzzy = false;
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(flags) = line.strip_prefix("#,") {
+            fuzzy = flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('"') {
+            let text = unquote(line).map_err(|err| format!("{} on line {}", err, index + 1))?;
+            match field {
+                Some(Field::Context) => message.context.get_or_insert_default().push_str(&text),
+                Some(Field::Id) => message.id.push_str(&text),
+                Some(Field::IdPlural) => message.id_plural.get_or_insert_default().push_str(&text),
+                Some(Field::Translation(n)) => message.translations[n].push_str(&text),
+                None => return Err(format!("Unexpected string on line {}", index + 1)),
+            }
+            continue;
+        }
+
+        let (keyword, value) = line
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| format!("Expected a keyword and a string on line {}", index + 1))?;
+        let text = unquote(value.trim()).map_err(|err| format!("{} on line {}", err, index + 1))?;
+        let starts_message = matches!(keyword, "msgctxt" | "msgid");
+        if starts_message && !message.translations.is_empty() {
+            messages.push(std::mem::take(&mut message));
+        }
+        if starts_message && message.context.is_none() && message.id.is_empty() {
+            message.fuzzy = std::mem::take(&mut fuzzy);
+        }
+        field = Some(match keyword {
+            "msgctxt" => {
+                message.context = Some(text);
+                Field::Context
+            }
+            "msgid" => {
+                message.id = text;
+                Field::Id
+            }
+            "msgid_plural" => {
+                message.id_plural = Some(text);
+                Field::IdPlural
+            }
+            "msgstr" => {
+                message.translations = vec![text];
+                Field::Translation(0)
+            }
+            keyword => {
+                let n = keyword
+                    .strip_prefix("msgstr[")
+                    .and_then(|rest| rest.strip_suffix(']'))
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .ok_or_else(|| format!("Unknown keyword {} on line {}", keyword, index + 1))?;
+                if message.translations.len() <= n {
+                    message.translations.resize(n + 1, String::new());
+                }
+                message.translations[n] = text;
+                Field::Translation(n)
+            }
+        });
+    }
+    if !message.translations.is_empty() {
+        messages.push(message);
+    }
+    Ok(to_translations(messages))
+}
+
+/// Parses a `.mo` file into its `(key, value)` translations
+pub fn parse_mo(bytes: &[u8]) -> Result<Vec<(String, String)>, String> {
+    let read_u32 = |offset: usize, little_endian: bool| -> Result<usize, String> {
+        let bytes: [u8; 4] = bytes
+            .get(offset..offset + 4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or("Unexpected end of file")?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        } as usize)
+    };
+    let little_endian = match read_u32(0, true)? {
+        0x950412de => true,
+        0xde120495 => false,
+        _ => return Err("Not a gettext .mo file".to_string()),
+    };
+    let count = read_u32(8, little_endian)?;
+    let originals = read_u32(12, little_endian)?;
+    let translations = read_u32(16, little_endian)?;
+    let read_string = |table: usize, index: usize| -> Result<&str, String> {
+        let length = read_u32(table + index * 8, little_endian)?;
+        let offset = read_u32(table + index * 8 + 4, little_endian)?;
+        let bytes = bytes
+            .get(offset..offset + length)
+            .ok_or("Unexpected end of file")?;
+        std::str::from_utf8(bytes).map_err(|err| err.to_string())
+    };
+
+    let mut messages = vec![];
+    for index in 0..count {
+        let original = read_string(originals, index)?;
+        let (context, original) = match original.split_once('\u{4}') {
+            Some((context, original)) => (Some(context.to_string()), original),
+            None => (None, original),
+        };
+        let mut ids = original.split('\0');
+        messages.push(Message {
+            context,
+            id: ids.next().unwrap_or_default().to_string(),
+            id_plural: ids.next().map(str::to_string),
+            translations: read_string(translations, index)?
+                .split('\0')
+                .map(str::to_string)
+                .collect(),
+            fuzzy: false,
+        });
+    }
+    Ok(to_translations(messages))
+}
+
+/// Maps the messages to their keys, using the `Plural-Forms` header of the file for the plural categories
+fn to_translations(messages: Vec<Message>) -> Vec<(String, String)> {
+    let header_forms = messages
+        .iter()
+        .find(|message| message.id.is_empty() && message.context.is_none())
+        .and_then(|header| {
+            header.translations.first()?.lines().find_map(|line| {
+                line.trim()
+                    .strip_prefix("Plural-Forms:")?
+                    .split(';')
+                    .find_map(|part| part.trim().strip_prefix("nplurals="))?
+                    .trim()
+                    .parse::<usize>()
+                    .ok()
+            })
+        });
+
+    let mut translations = vec![];
+    for message in messages {
+        if message.id.is_empty() || message.fuzzy {
+            continue;
+        }
+        let key = match message.context.as_ref() {
+            Some(context) => format!("{}.{}", message.id, context),
+            None => message.id.clone(),
+        };
+        if message.id_plural.is_none() {
+            if let Some(value) = message
+                .translations
+                .first()
+                .filter(|value| !value.is_empty())
+            {
+                translations.push((key, placeholders(value)));
+            }
+            continue;
+        }
+        let categories = plural_categories(header_forms.unwrap_or(message.translations.len()));
+        for (index, value) in message.translations.iter().enumerate() {
+            if value.is_empty() {
+                continue;
+            }
+            // Extra forms are dropped, missing ones resolve to `other`
+            let category = if index + 1 == message.translations.len() {
+                "other"
+            } else {
+                match categories.get(index) {
+                    Some(category) if *category != "other" => category,
+                    _ => continue,
+                }
+            };
+            translations.push((format!("{}.{}", key, category), placeholders(value)));
+        }
+    }
+    translations
+}
+
+fn plural_categories(forms: usize) -> &'static [&'static str] {
+    match forms {
+        0 | 1 => &["other"],
+        2 => &["one", "other"],
+        3 => &["one", "few", "other"],
+        4 => &["one", "two", "few", "other"],
+        5 => &["one", "two", "few", "many", "other"],
+        _ => &["zero", "one", "two", "few", "many", "other"],
+    }
+}
+
+/// Replaces the Python format placeholders, `%(name)s` -> `{name}`
+fn placeholders(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("%(") {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let placeholder = rest[2..].split_once(')').and_then(|(name, after)| {
+            let conversion = after.chars().next().filter(char::is_ascii_alphabetic)?;
+            Some((name, conversion.len_utf8() + name.len() + 3))
+        });
+        match placeholder {
+            Some((name, length)) => {
+                result.push('{');
+                result.push_str(name);
+                result.push('}');
+                rest = &rest[length..];
+            }
+            None => {
+                result.push_str("%(");
+                rest = &rest[2..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Unescapes a quoted `.po` string
+fn unquote(text: &str) -> Result<String, String> {
+    let inner = text
+        .strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .ok_or("Expected a quoted string")?;
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some(c) => result.push(c),
+            None => return Err("Unterminated escape sequence".to_string()),
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    /// Builds a little endian `.mo` file of the `(original, translation)` strings
+    fn mo(strings: &[(&str, &str)]) -> Vec<u8> {
+        let originals = 28;
+        let translations = originals + strings.len() * 8;
+        let mut offset = translations + strings.len() * 8;
+        let mut tables = vec![];
+        let mut data = vec![];
+        for text in strings
+            .iter()
+            .map(|(original, _)| original)
+            .chain(strings.iter().map(|(_, translation)| translation))
+        {
+            tables.extend((text.len() as u32).to_le_bytes());
+            tables.extend((offset as u32).to_le_bytes());
+            data.extend(text.as_bytes());
+            data.push(0);
+            offset += text.len() + 1;
+        }
+        let mut bytes = vec![];
+        for value in [0x950412de, 0, strings.len(), originals, translations, 0, 0] {
+            bytes.extend((value as u32).to_le_bytes());
+        }
+        bytes.extend(tables);
+        bytes.extend(data);
+        bytes
+    }
+
+    #[test]
+    fn po_messages_contexts_and_placeholders() {
+        let content = r#"
+msgid ""
+msgstr "Plural-Forms: nplurals=2; plural=(n != 1);\n"
+
+# A translator comment
+msgid "hello"
+msgstr "Hello, %(name)s!"
+
+msgctxt "menu"
+msgid "open"
+msgstr ""
+"Open "
+"file"
+
+#, fuzzy
+msgid "draft"
+msgstr "Draft"
+
+msgid "untranslated"
+msgstr ""
+"#;
+        assert_eq!(
+            parse_po(content).unwrap(),
+            [
+                translation("hello", "Hello, {name}!"),
+                translation("open.menu", "Open file"),
+            ]
+        );
+    }
+
+    #[test]
+    fn po_plural_forms_follow_the_header() {
+        let content = r#"
+msgid ""
+msgstr "Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : 1);\n"
+
+msgctxt "inbox"
+msgid "email"
+msgid_plural "emails"
+msgstr[0] "%(count)d email"
+msgstr[1] "%(count)d emails"
+msgstr[2] "%(count)d e-mails"
+"#;
+        assert_eq!(
+            parse_po(content).unwrap(),
+            [
+                translation("email.inbox.one", "{count} email"),
+                translation("email.inbox.few", "{count} emails"),
+                translation("email.inbox.other", "{count} e-mails"),
+            ]
+        );
+    }
+
+    #[test]
+    fn po_plural_forms_without_header_use_the_form_count() {
+        let content =
+            "msgid \"apple\"\nmsgid_plural \"apples\"\nmsgstr[0] \"apple\"\nmsgstr[1] \"apples\"\n";
+        assert_eq!(
+            parse_po(content).unwrap(),
+            [
+                translation("apple.one", "apple"),
+                translation("apple.other", "apples"),
+            ]
+        );
+    }
+
+    #[test]
+    fn po_errors_report_their_line() {
+        assert_eq!(
+            parse_po("msgid \"hello\"\nmsgstr \"Hello\\\"\n").unwrap_err(),
+            "Unterminated escape sequence on line 2"
+        );
+        assert_eq!(
+            parse_po("msgid \"hello\"\nmsgstr Hello\n").unwrap_err(),
+            "Expected a quoted string on line 2"
+        );
+    }
+
+    #[test]
+    fn mo_messages_contexts_and_plurals() {
+        let bytes = mo(&[
+            ("", "Plural-Forms: nplurals=2; plural=(n != 1);\n"),
+            ("apple\0apples", "apple\0%(count)d apples"),
+            ("hello", "Hello"),
+            ("menu\u{4}open", "Open"),
+        ]);
+        assert_eq!(
+            parse_mo(&bytes).unwrap(),
+            [
+                translation("apple.one", "apple"),
+                translation("apple.other", "{count} apples"),
+                translation("hello", "Hello"),
+                translation("open.menu", "Open"),
+            ]
+        );
+        assert_eq!(
+            parse_mo(b"not a mo file").unwrap_err(),
+            "Not a gettext .mo file"
+        );
+        assert_eq!(
+            parse_mo(&bytes[..40]).unwrap_err(),
+            "Unexpected end of file"
+        );
+    }
+}
@@ -0,0 +1,41 @@
+//! Locale of a locale file from its path, shared by the build script and the runtime loader
+//!
+//! Same as `rust-i18n`, `app.en.yml` and `en.yml` are both treated as `en`. A file without a locale in its name
+//! that's in a locale folder, a folder of the translation root named after a locale, is treated as the locale of the folder,
+//! `en/menu.yml` and `pt-BR/hud/inventory.yml`
+
+use std::path::Path;
+
+/// Locale of a locale file, the path is relative to its translation root
+pub fn locale_of(path: &Path) -> &str {
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    match stem.rsplit_once('.') {
+        Some((_, locale)) => locale,
+        None => folder_locale(path).unwrap_or(stem),
+    }
+}
+
+/// Locale folder of a file without a locale in its name, `en` for `en/menu.yml`,
+/// the path is relative to its translation root
+pub fn folder_locale(path: &Path) -> Option<&str> {
+    let stem = path.file_stem()?.to_str()?;
+    if stem.contains('.') || path.components().count() < 2 {
+        return None;
+    }
+    let folder = path.components().next()?.as_os_str().to_str()?;
+    is_locale_code(folder).then_some(folder)
+}
+
+/// Whether a folder or column name is a locale code, `en`, `pt-BR`, `zh_TW` or `zh-Hant-TW`
+pub fn is_locale_code(name: &str) -> bool {
+    let mut subtags = name.split(['-', '_']);
+    let language = subtags.next().unwrap_or_default();
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && subtags.all(|subtag| {
+            (2..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
@@ -0,0 +1,818 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Folder of the locale files embedded by `rust_i18n::i18n!`, relative to the crate root
+pub const LOCALES_PATH: &str = "assets/locales";
+
+const PLURAL_CATEGORIES: &[&str] = &["zero", "one", "two", "few", "many", "other"];
+const LOCALE_EXTENSIONS: &[&str] = &["yml", "yaml", "json", "toml"];
+/// Extension of the Fluent files, only parsed with the `fluent` feature
+const FLUENT_EXTENSION: &str = "ftl";
+/// Extensions of the gettext source and compiled files, only parsed with the `gettext` feature
+const GETTEXT_EXTENSIONS: &[&str] = &["po", "mo"];
+/// Extension of the spreadsheet files holding every locale, only parsed with the `csv` feature
+const CSV_EXTENSION: &str = "csv";
+
+/// Formats that `rust-i18n` doesn't support, which are parsed here and embedded by the crate itself
+pub struct Backends {
+    pub fluent: bool,
+    pub gettext: bool,
+    /// Columns of the CSV files, `None` without the `csv` feature
+    pub csv: Option<crate::csv::Columns>,
+}
+
+impl Backends {
+    fn parses(&self, ext: &str) -> bool {
+        (self.fluent && ext == FLUENT_EXTENSION)
+            || (self.gettext && GETTEXT_EXTENSIONS.contains(&ext))
+            || (self.csv.is_some() && ext == CSV_EXTENSION)
+    }
+}
+
+fn is_backend_extension(ext: &str) -> bool {
+    ext == FLUENT_EXTENSION || GETTEXT_EXTENSIONS.contains(&ext) || ext == CSV_EXTENSION
+}
+
+/// A translation and the locale file it's defined in
+pub struct Translation {
+    pub value: String,
+    pub file: PathBuf,
+    /// Element of a list, `tips.0`, which `rust-i18n` doesn't embed
+    pub list: bool,
+    /// Index of the translation root of the file, `0` for the [LOCALES_PATH] folder that `rust-i18n` embeds
+    pub root: usize,
+    /// Defined in a locale folder, `en/menu.yml`, which `rust-i18n` reads as the `menu` locale
+    pub folder: bool,
+}
+
+/// Flattened translations of every locale file, `locale -> key -> translation`
+pub type Translations = BTreeMap<String, BTreeMap<String, Translation>>;
+
+/// Parses every locale file of the folder the same way `rust-i18n` does,
+/// nested tables and objects are flattened into dotted keys and the namespaces of every file are merged
+///
+/// The Fluent, gettext and CSV files are also parsed when their backend is enabled,
+/// see [crate::fluent], [crate::gettext] and [crate::csv]
+///
+/// Returns an error listing every key that's defined more than once for the same locale,
+/// either in different files or twice in the same file, e.g. as a dotted key and a nested table
+pub fn load(dir: &Path, backends: &Backends) -> Result<Translations, String> {
+    let mut translations = Translations::new();
+    let mut collisions = vec![];
+
+    let mut files = visit_dirs(dir);
+    files.sort();
+    for file in files.iter() {
+        let Some(ext) = file.extension().and_then(|e| e.to_str()) else {
+            continue;
+        };
+        let is_backend = backends.parses(ext);
+        if !LOCALE_EXTENSIONS.contains(&ext) && !is_backend {
+            continue;
+        }
+        cargo_emit::rerun_if_changed!(file.to_string_lossy());
+        let bytes = fs::read(file)
+            .map_err(|err| format!("Could not read {}: {}", source_path(dir, file), err))?;
+        let relative = file.strip_prefix(dir).unwrap_or(file);
+        let locale = crate::locale_path::locale_of(relative);
+        let folder = !is_backend && crate::locale_path::folder_locale(relative).is_some();
+
+        let mut entries = vec![];
+        if is_backend {
+            let with_locale = |table: Vec<(String, String)>| {
+                table
+                    .into_iter()
+                    .map(|(key, value)| (locale.to_string(), key, value))
+                    .collect()
+            };
+            let table = match (ext, backends.csv.as_ref()) {
+                (CSV_EXTENSION, Some(columns)) => {
+                    utf8(&bytes).and_then(|content| crate::csv::parse(content, columns))
+                }
+                (FLUENT_EXTENSION, _) => {
+                    utf8(&bytes).and_then(crate::fluent::parse).map(with_locale)
+                }
+                ("po", _) => utf8(&bytes)
+                    .and_then(crate::gettext::parse_po)
+                    .map(with_locale),
+                _ => crate::gettext::parse_mo(&bytes).map(with_locale),
+            }
+            .map_err(|err| format!("Could not parse {}: {}", source_path(dir, file), err))?;
+            entries.extend(
+                table
+                    .into_iter()
+                    .map(|(locale, key, value)| (locale, key, value, false)),
+            );
+            add_entries(
+                &mut translations,
+                &mut collisions,
+                dir,
+                file,
+                false,
+                entries,
+            );
+            continue;
+        }
+        let content = utf8(&bytes)
+            .map_err(|err| format!("Could not read {}: {}", source_path(dir, file), err))?;
+        let value = parse(content, ext)
+            .map_err(|err| format!("Could not parse {}: {}", source_path(dir, file), err))?;
+
+        if value.get("_version").and_then(|v| v.as_u64()) == Some(2) {
+            if let serde_json::Value::Object(root) = &value {
+                for (key, value) in root.iter().filter(|(key, _)| *key != "_version") {
+                    if let serde_json::Value::Object(locales) = value {
+                        parse_v2(key, locales, &mut entries);
+                    }
+                }
+            }
+        } else {
+            let mut table = vec![];
+            flatten("", &value, false, &mut table);
+            entries.extend(
+                table
+                    .into_iter()
+                    .filter(|(key, _, _)| key != "_version")
+                    .map(|(key, value, list)| (locale.to_string(), key, value, list)),
+            );
+        }
+
+        add_entries(
+            &mut translations,
+            &mut collisions,
+            dir,
+            file,
+            folder,
+            entries,
+        );
+    }
+
+    if collisions.is_empty() {
+        Ok(translations)
+    } else {
+        Err(format!(
+            "Colliding translation keys:\n  {}",
+            collisions.join("\n  ")
+        ))
+    }
+}
+
+/// Parses the locale files of every translation root with [load] and merges them per locale,
+/// the keys of the later roots override the ones of the earlier roots instead of colliding
+///
+/// A list of a later root replaces the whole list, so it can have fewer elements
+pub fn load_roots(dirs: &[PathBuf], backends: &Backends) -> Result<Translations, String> {
+    let mut translations = Translations::new();
+    for (root, dir) in dirs.iter().enumerate() {
+        cargo_emit::rerun_if_changed!(dir.to_string_lossy());
+        for (locale, table) in load(dir, backends)? {
+            let merged = translations.entry(locale).or_default();
+            for parent in table
+                .iter()
+                .filter(|(_, translation)| translation.list)
+                .filter_map(|(key, _)| {
+                    key.rsplit_once('.')
+                        .map(|(parent, _)| format!("{}.", parent))
+                })
+                .collect::<BTreeSet<_>>()
+            {
+                merged.retain(|key, translation| !(translation.list && key.starts_with(&parent)));
+            }
+            for (key, mut translation) in table {
+                translation.root = root;
+                merged.insert(key, translation);
+            }
+        }
+    }
+    Ok(translations)
+}
+
+/// Adds the `(locale, key, value, list)` entries of a file, recording the keys that are already defined
+fn add_entries(
+    translations: &mut Translations,
+    collisions: &mut Vec<String>,
+    dir: &Path,
+    file: &Path,
+    folder: bool,
+    entries: Vec<(String, String, String, bool)>,
+) {
+    for (locale, key, value, list) in entries {
+        let table = translations.entry(locale.clone()).or_default();
+        if let Some(existing) = table.get(&key) {
+            collisions.push(format!(
+                "`{}` for `{}` is defined in both {} and {}",
+                key,
+                locale,
+                existing
+                    .file
+                    .strip_prefix(dir)
+                    .unwrap_or(&existing.file)
+                    .display(),
+                file.strip_prefix(dir).unwrap_or(file).display()
+            ));
+            continue;
+        }
+        table.insert(
+            key,
+            Translation {
+                value,
+                file: file.to_path_buf(),
+                list,
+                root: 0,
+                folder,
+            },
+        );
+    }
+}
+
+/// Describes the keys that each locale is missing compared to the reference locale, and the keys it has that the reference doesn't,
+/// `fr is missing 2 keys of en: menu.quit, menu.start`
+///
+/// A locale includes the keys of its less specific variants, `pt-BR` has the keys of `pt`,
+/// and the plural forms and list elements of a key count as the key itself since their number depends on the language.
+/// Metadata keys such as `_native_name` are skipped
+pub fn consistency_report(translations: &Translations, reference: &str) -> Vec<String> {
+    let Some(reference_keys) = translations.get(reference).map(|table| logical_keys(table)) else {
+        return vec![format!(
+            "The reference locale {} has no translations, available locales: {}",
+            reference,
+            translations.keys().cloned().collect::<Vec<_>>().join(", ")
+        )];
+    };
+    let mut report = vec![];
+    for locale in translations.keys().filter(|locale| *locale != reference) {
+        let mut keys = BTreeSet::new();
+        let mut current = locale.as_str();
+        loop {
+            if let Some(table) = translations.get(current) {
+                keys.extend(logical_keys(table));
+            }
+            match current.rfind('-') {
+                Some(index) => current = &current[..index],
+                None => break,
+            }
+        }
+        let missing: Vec<&str> = reference_keys.difference(&keys).copied().collect();
+        if !missing.is_empty() {
+            report.push(format!(
+                "{} is missing {} keys of {}: {}",
+                locale,
+                missing.len(),
+                reference,
+                missing.join(", ")
+            ));
+        }
+        let extra: Vec<&str> = logical_keys(&translations[locale])
+            .difference(&reference_keys)
+            .copied()
+            .collect();
+        if !extra.is_empty() {
+            report.push(format!(
+                "{} has {} keys that aren't in {}: {}",
+                locale,
+                extra.len(),
+                reference,
+                extra.join(", ")
+            ));
+        }
+    }
+    report
+}
+
+/// Keys of a locale without their plural category or list index, `items.one` -> `items`, `tips.0` -> `tips`
+fn logical_keys(table: &BTreeMap<String, Translation>) -> BTreeSet<&str> {
+    table
+        .iter()
+        .filter(|(key, _)| !key.starts_with('_'))
+        .map(|(key, translation)| match key.rsplit_once('.') {
+            Some((parent, last)) if translation.list || PLURAL_CATEGORIES.contains(&last) => parent,
+            _ => key.as_str(),
+        })
+        .collect()
+}
+
+/// Writes the translations of the Fluent, gettext and CSV files as the `BACKEND_TRANSLATIONS` table, sorted by locale and key
+pub fn write_backend_table(translations: &Translations) -> String {
+    let locales = translations
+        .iter()
+        .filter_map(|(locale, table)| {
+            let entries: Vec<String> = table
+                .iter()
+                .filter(|(_, translation)| {
+                    translation.root == 0
+                        && !translation.folder
+                        && translation
+                            .file
+                            .extension()
+                            .and_then(|e| e.to_str())
+                            .is_some_and(is_backend_extension)
+                })
+                .map(|(key, translation)| format!("({:?}, {:?})", key, translation.value))
+                .collect();
+            (!entries.is_empty()).then(|| format!("    ({:?}, &[{}]),", locale, entries.join(", ")))
+        })
+        .collect::<Vec<_>>();
+    format!(
+        r#"/// Translations of the Fluent, gettext and CSV files, `(locale, [(key, value)])` sorted by locale and key
+pub(crate) static BACKEND_TRANSLATIONS: &[(&str, &[(&str, &str)])] = &[
+{}
+];
+"#,
+        locales.join("\n")
+    )
+}
+
+/// Writes the translations of the extra translation roots as the `ROOT_TRANSLATIONS` table, sorted by locale and key,
+/// which take precedence over the ones of the [LOCALES_PATH] folder, along with the ones of its locale folders
+pub fn write_root_table(translations: &Translations) -> String {
+    let locales = translations
+        .iter()
+        .filter_map(|(locale, table)| {
+            let entries: Vec<String> = table
+                .iter()
+                .filter(|(_, translation)| translation.root > 0 || translation.folder)
+                .map(|(key, translation)| format!("({:?}, {:?})", key, translation.value))
+                .collect();
+            (!entries.is_empty()).then(|| format!("    ({:?}, &[{}]),", locale, entries.join(", ")))
+        })
+        .collect::<Vec<_>>();
+    format!(
+        r#"/// Translations of the extra translation roots and the locale folders, `(locale, [(key, value)])` sorted by locale and key
+pub(crate) static ROOT_TRANSLATIONS: &[(&str, &[(&str, &str)])] = &[
+{}
+];
+"#,
+        locales.join("\n")
+    )
+}
+
+/// Writes the elements of the lists as the `LIST_TRANSLATIONS` table, sorted by locale and key
+pub fn write_list_table(translations: &Translations) -> String {
+    let locales = translations
+        .iter()
+        .filter_map(|(locale, table)| {
+            let entries: Vec<String> = table
+                .iter()
+                .filter(|(_, translation)| {
+                    translation.list && translation.root == 0 && !translation.folder
+                })
+                .map(|(key, translation)| format!("({:?}, {:?})", key, translation.value))
+                .collect();
+            (!entries.is_empty()).then(|| format!("    ({:?}, &[{}]),", locale, entries.join(", ")))
+        })
+        .collect::<Vec<_>>();
+    format!(
+        r#"/// Elements of the lists of the locale files, `(locale, [(key, value)])` sorted by locale and key
+pub(crate) static LIST_TRANSLATIONS: &[(&str, &[(&str, &str)])] = &[
+{}
+];
+"#,
+        locales.join("\n")
+    )
+}
+
+/// Writes the keys of every locale as the `LOCALE_KEYS` table, sorted by locale and key,
+/// used to build the resolved translations of a locale
+pub fn write_locale_keys(translations: &Translations) -> String {
+    let locales = translations
+        .iter()
+        .map(|(locale, table)| {
+            format!(
+                "    ({:?}, &{:?}),",
+                locale,
+                table.keys().collect::<Vec<_>>()
+            )
+        })
+        .collect::<Vec<_>>();
+    format!(
+        r#"/// Keys of the embedded translations, `(locale, [key])` sorted by locale and key
+pub(crate) static LOCALE_KEYS: &[(&str, &[&str])] = &[
+{}
+];
+"#,
+        locales.join("\n")
+    )
+}
+
+/// Writes the unique characters of the translations of every locale, used to warm up the glyph atlas
+pub fn write_glyph_table(translations: &Translations) -> String {
+    let locales = translations
+        .iter()
+        .map(|(locale, table)| {
+            let glyphs: std::collections::BTreeSet<char> = table
+                .values()
+                .flat_map(|translation| translation.value.chars())
+                .filter(|c| !c.is_whitespace() && !c.is_control())
+                .collect();
+            format!(
+                "    ({:?}, {:?}),",
+                locale,
+                glyphs.into_iter().collect::<String>()
+            )
+        })
+        .collect::<Vec<_>>();
+    format!(
+        r#"/// Unique characters of the embedded translations, `(locale, characters)` sorted by locale
+pub(crate) static LOCALE_GLYPHS: &[(&str, &str)] = &[
+{}
+];
+"#,
+        locales.join("\n")
+    )
+}
+
+/// Writes the `__i18n_key!` macro that only compiles for known keys, used by the `t!` macro
+///
+/// Accepts every key of every locale, along with the parent keys of plural and context variants, `messages.items` for `messages.items.one`
+pub fn write_key_validation(translations: &Translations) -> String {
+    let mut keys = std::collections::BTreeSet::new();
+    for key in translations.values().flat_map(|table| table.keys()) {
+        let mut current = key.as_str();
+        keys.insert(current);
+        while let Some(index) = current.rfind('.') {
+            current = &current[..index];
+            keys.insert(current);
+        }
+    }
+    format!(
+        r#"#[doc(hidden)]
+#[macro_export]
+macro_rules! __i18n_key {{
+{}
+    ($key:literal) => {{
+        compile_error!(concat!("Unknown translation key: ", $key))
+    }};
+}}
+"#,
+        keys.iter()
+            .map(|key| format!("    ({:?}) => {{}};", key))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+}
+
+/// Constants of the keys of a module of the `keys` module, nested by the segments of the keys
+#[derive(Default)]
+struct KeyModule {
+    constants: BTreeMap<String, String>,
+    modules: BTreeMap<String, KeyModule>,
+}
+
+impl KeyModule {
+    fn write(&self, output: &mut String, depth: usize) {
+        let indent = "    ".repeat(depth);
+        for (name, key) in self.constants.iter() {
+            output.push_str(&format!(
+                "{indent}/// `{key}`\n{indent}pub const {name}: &str = {key:?};\n"
+            ));
+        }
+        for (name, module) in self.modules.iter() {
+            output.push_str(&format!("{indent}pub mod {name} {{\n"));
+            module.write(output, depth + 1);
+            output.push_str(&format!("{indent}}}\n"));
+        }
+    }
+}
+
+/// Writes the keys of every locale as constants of nested modules, `menu.start` -> `menu::START`,
+/// along with the parent keys of plural variants, `messages.items` for `messages.items.one`
+///
+/// The segments are sanitized into identifiers, `main-menu` -> `main_menu`, `2d` -> `_2d`, `type` -> `type_`,
+/// the keys whose constant collides with another one are skipped and returned
+pub fn write_keys_module(translations: &Translations) -> (String, Vec<String>) {
+    let mut keys: BTreeSet<&str> = BTreeSet::new();
+    for key in translations.values().flat_map(|table| table.keys()) {
+        keys.insert(key);
+        if let Some((parent, category)) = key.rsplit_once('.') {
+            if PLURAL_CATEGORIES.contains(&category) {
+                keys.insert(parent);
+            }
+        }
+    }
+
+    let mut root = KeyModule::default();
+    let mut collisions = vec![];
+    for key in keys {
+        let mut segments: Vec<&str> = key.split('.').collect();
+        let constant = identifier(segments.pop().unwrap_or_default(), true);
+        let module = segments.into_iter().fold(&mut root, |module, segment| {
+            module
+                .modules
+                .entry(identifier(segment, false))
+                .or_default()
+        });
+        match module.constants.get(&constant) {
+            Some(other) => collisions.push(format!("{} and {} are both {}", other, key, constant)),
+            None => {
+                module.constants.insert(constant, key.to_string());
+            }
+        }
+    }
+
+    let mut output = String::new();
+    root.write(&mut output, 0);
+    (output, collisions)
+}
+
+/// Identifier of a segment of a key, uppercased for constants and lowercased for modules,
+/// the characters that can't be in an identifier separate its words
+fn identifier(segment: &str, constant: bool) -> String {
+    let mut name = segment
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            if constant {
+                word.to_ascii_uppercase()
+            } else {
+                word.to_ascii_lowercase()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("_");
+    if name.is_empty() {
+        name.push_str("__");
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    if !constant && KEYWORDS.contains(&name.as_str()) {
+        name.push('_');
+    }
+    name
+}
+
+/// Keywords that can't be used as module names, including the reserved ones
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Writes the placeholders that the `t!` macro expects arguments for as the `PLACEHOLDERS` table, sorted by key
+///
+/// A key expects the placeholders of its translations in every locale,
+/// or of its plural forms when it's only their parent, `messages.items` for `messages.items.one`
+pub fn write_placeholder_table(translations: &Translations) -> String {
+    let mut table: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    let leaves: BTreeSet<&str> = translations
+        .values()
+        .flat_map(|table| table.keys().map(String::as_str))
+        .collect();
+    for (key, locale_table, translation) in translations.values().flat_map(|table| {
+        table
+            .iter()
+            .map(move |(key, translation)| (key, table, translation))
+    }) {
+        let names = placeholders(locale_table, &translation.value);
+        if names.is_empty() {
+            continue;
+        }
+        table.entry(key).or_default().extend(names.iter());
+        if let Some((parent, category)) = key.rsplit_once('.') {
+            if PLURAL_CATEGORIES.contains(&category) && !leaves.contains(parent) {
+                table.entry(parent).or_default().extend(names.iter());
+            }
+        }
+    }
+    format!(
+        r#"/// Placeholders of the translations, `(key, [name])` sorted by key
+static PLACEHOLDERS: &[(&str, &[&str])] = &[
+{}
+];
+"#,
+        table
+            .iter()
+            .map(|(key, names)| format!("    ({:?}, &{:?}),", key, Vec::from_iter(names)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+}
+
+/// Describes the keys whose placeholders differ between locales, `greet: {name} in en, {nom} in fr`
+///
+/// The `zero`, `one` and `two` plural forms are skipped since they often spell out their number
+pub fn placeholder_mismatches(translations: &Translations) -> Vec<String> {
+    let mut keys: BTreeMap<&str, Vec<(&str, BTreeSet<&str>)>> = BTreeMap::new();
+    for (locale, table) in translations.iter() {
+        for (key, translation) in table.iter() {
+            if key
+                .rsplit_once('.')
+                .is_some_and(|(_, last)| matches!(last, "zero" | "one" | "two"))
+            {
+                continue;
+            }
+            keys.entry(key)
+                .or_default()
+                .push((locale, placeholders(table, &translation.value)));
+        }
+    }
+    keys.into_iter()
+        .filter(|(_, locales)| locales.iter().any(|(_, names)| *names != locales[0].1))
+        .map(|(key, locales)| {
+            let locales = locales
+                .iter()
+                .map(|(locale, names)| {
+                    let names = names
+                        .iter()
+                        .map(|name| format!("{{{}}}", name))
+                        .collect::<Vec<_>>();
+                    if names.is_empty() {
+                        format!("none in {}", locale)
+                    } else {
+                        format!("{} in {}", names.join(" "), locale)
+                    }
+                })
+                .collect::<Vec<_>>();
+            format!("{}: {}", key, locales.join(", "))
+        })
+        .collect()
+}
+
+/// Names of the `{name}` and `%{name}` placeholders of a translation, following the interpolation of the crate
+///
+/// The name of a formatted placeholder, `{damage:0.0}`, and the argument of a select expression,
+/// `{gender, select, male {He} other {They}}`, are placeholders
+/// along with the placeholders of its branches. Braces that don't surround an identifier, e.g. `{ }`, aren't placeholders.
+/// A reference to another key of the locale, `${app.name}`, has the placeholders of its translation
+fn placeholders<'a>(table: &'a BTreeMap<String, Translation>, value: &'a str) -> BTreeSet<&'a str> {
+    let mut names = BTreeSet::new();
+    collect_placeholders(table, value, 0, &mut names);
+    names
+}
+
+/// Depth of nested references after which the runtime leaves them as-is
+const MAX_REFERENCE_DEPTH: usize = 8;
+
+fn collect_placeholders<'a>(
+    table: &'a BTreeMap<String, Translation>,
+    value: &'a str,
+    depth: usize,
+    names: &mut BTreeSet<&'a str>,
+) {
+    let mut rest = value;
+    while let Some(start) = rest.find('{') {
+        let reference = rest[..start].ends_with('$');
+        rest = &rest[start..];
+        let Some(end) = closing_brace(rest) else {
+            rest = &rest[1..];
+            continue;
+        };
+        let content = &rest[1..end];
+        // `${app.name}` references another key, or is a `{name}` placeholder after a `$` when the key doesn't exist
+        let referenced = table.get(content.trim()).filter(|_| reference);
+        if let Some(translation) = referenced {
+            if depth < MAX_REFERENCE_DEPTH {
+                collect_placeholders(table, &translation.value, depth + 1, names);
+            }
+            rest = &rest[end + 1..];
+            continue;
+        }
+        // `{damage:0.0}` formats the `damage` number
+        let name = content.split_once(':').map_or(content, |(name, _)| name);
+        if is_identifier(name) {
+            names.insert(name);
+        } else if let Some((name, branches)) = select_expression(content) {
+            names.insert(name);
+            for branch in branches {
+                collect_placeholders(table, branch, depth, names);
+            }
+        } else {
+            collect_placeholders(table, content, depth, names);
+        }
+        rest = &rest[end + 1..];
+    }
+}
+
+/// Argument and branches of a select expression, `gender, select, male {He} other {They}`
+fn select_expression(content: &str) -> Option<(&str, Vec<&str>)> {
+    let (name, rest) = content.split_once(',')?;
+    let (kind, mut rest) = rest.split_once(',')?;
+    let name = name.trim();
+    if !is_identifier(name) || !matches!(kind.trim(), "select" | "plural") {
+        return None;
+    }
+    let mut branches = vec![];
+    while !rest.trim().is_empty() {
+        let start = rest.find('{')?;
+        let key = rest[..start].trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            return None;
+        }
+        let end = start + closing_brace(&rest[start..])?;
+        branches.push(&rest[start + 1..end]);
+        rest = &rest[end + 1..];
+    }
+    (!branches.is_empty()).then_some((name, branches))
+}
+
+/// Index of the brace closing the one the text starts with
+fn closing_brace(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (index, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 1 => return Some(index),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Path of a locale file relative to the crate root, `assets/locales/hud.en.yml`, for the build errors
+fn source_path(dir: &Path, file: &Path) -> String {
+    file.strip_prefix(dir)
+        .map(|relative| Path::new(LOCALES_PATH).join(relative))
+        .unwrap_or_else(|_| file.to_path_buf())
+        .display()
+        .to_string()
+}
+
+fn utf8(bytes: &[u8]) -> Result<&str, String> {
+    std::str::from_utf8(bytes).map_err(|err| err.to_string())
+}
+
+fn parse(content: &str, ext: &str) -> Result<serde_json::Value, String> {
+    match ext {
+        "yml" | "yaml" => serde_yaml::from_str(content).map_err(|err| err.to_string()),
+        "json" => serde_json::from_str(content).map_err(|err| err.to_string()),
+        "toml" => toml::from_str(content).map_err(|err| err.to_string()),
+        _ => Err(format!("Unsupported extension: {}", ext)),
+    }
+}
+
+/// Parses a `_version: 2` entry, where the leaves are `locale: value` pairs
+fn parse_v2(
+    key: &str,
+    locales: &serde_json::Map<String, serde_json::Value>,
+    entries: &mut Vec<(String, String, String, bool)>,
+) {
+    for (locale, value) in locales {
+        match value {
+            serde_json::Value::String(text) => {
+                entries.push((locale.clone(), key.to_string(), text.clone(), false));
+            }
+            serde_json::Value::Object(nested) => {
+                parse_v2(&format!("{}.{}", key, locale), nested, entries);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Flattens nested objects into dotted keys and lists into indexed keys, `tips.0`, `tips.1`
+fn flatten(
+    prefix: &str,
+    value: &serde_json::Value,
+    list: bool,
+    table: &mut Vec<(String, String, bool)>,
+) {
+    let key = |key: &dyn std::fmt::Display| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", prefix, key)
+        }
+    };
+    match value {
+        serde_json::Value::Object(map) => {
+            for (name, value) in map {
+                flatten(&key(name), value, list, table);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                flatten(&key(&index), value, true, table);
+            }
+        }
+        serde_json::Value::String(text) => table.push((prefix.to_string(), text.clone(), list)),
+        serde_json::Value::Null => table.push((prefix.to_string(), String::new(), list)),
+        other => table.push((prefix.to_string(), other.to_string(), list)),
+    }
+}
+
+fn visit_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut collected = vec![];
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collected.append(&mut visit_dirs(&path));
+            } else {
+                collected.push(path);
+            }
+        }
+    }
+    collected
+}
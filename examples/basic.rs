@@ -6,13 +6,13 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         // Add the base plugin
-        .add_plugins(I18nPlugin)
+        .add_plugins(I18nPlugin::default())
         .add_systems(Startup, setup)
         .run();
 }
 
 fn setup(mut commands: Commands) {
-    commands.spawn(Camera2d::default());
+    commands.spawn(Camera2d);
     commands
         .spawn(Node {
             width: Val::Percent(100.),
@@ -5,14 +5,14 @@ use bevy_simple_i18n::prelude::*;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(I18nPlugin)
+        .add_plugins(I18nPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, button_system)
         .run();
 }
 
 fn setup(mut commands: Commands, i18n_res: Res<I18n>) {
-    commands.spawn(Camera2d::default());
+    commands.spawn(Camera2d);
     commands
         .spawn(Node {
             width: Val::Percent(100.),
@@ -81,6 +81,21 @@ fn setup(mut commands: Commands, i18n_res: Res<I18n>) {
                     ));
                 });
 
+            // Example that shows pluralization based on a count
+            parent
+                .spawn(Node {
+                    display: Display::Flex,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(Text::new("'messages.items' (3) => "));
+                    parent.spawn((
+                        I18nText::new("messages.items").with_count(3),
+                        I18nFont::new("NotoSans"),
+                    ));
+                });
+
             // Spawns a node with a text that will always be in Japanese
             parent
                 .spawn(Node {
@@ -136,18 +151,20 @@ fn setup(mut commands: Commands, i18n_res: Res<I18n>) {
         });
 }
 
+type ButtonQuery<'w, 's> =
+    Query<'w, 's, (&'static Interaction, &'static Children), (Changed<Interaction>, With<Button>)>;
+
 fn button_system(
-    interaction_query: Query<(&Interaction, &Children), (Changed<Interaction>, With<Button>)>,
+    interaction_query: ButtonQuery,
     text_query: Query<&Text>,
     mut i18n_res: ResMut<I18n>,
 ) {
     for (interaction, children) in interaction_query.iter() {
-        match *interaction {
-            Interaction::Pressed => {
-                let text = text_query.get(children[0]).unwrap().clone().0;
-                i18n_res.set_locale(text);
+        if *interaction == Interaction::Pressed {
+            let text = text_query.get(children[0]).unwrap().clone().0;
+            if let Err(err) = i18n_res.set_locale(text) {
+                error!("{}", err);
             }
-            _ => {}
         }
     }
 }
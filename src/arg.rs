@@ -0,0 +1,65 @@
+//! Interpolation arguments of the translation methods, either a value or a translation key
+
+use std::fmt::Display;
+
+/// Interpolation argument of [crate::prelude::I18n::t_with], a value or a key translated in the same locale
+///
+/// `i18n.t_with("combat.hit", &[("type", Arg::key("damage.fire"))])` -> `You take fire damage`, `Vous subissez des dégâts de feu`.
+/// Select expressions choose their branch from the translated word, `{type, select, glace {...} other {...}}`,
+/// so the sentence agrees with it
+///
+/// Arguments of different kinds are mixed with [Arg::from], `&[("type", Arg::key("damage.fire")), ("amount", Arg::from(12))]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Arg {
+    /// Value interpolated as-is
+    Value(String),
+    /// Translation key whose translation is interpolated
+    Key(String),
+}
+
+impl Arg {
+    /// Argument resolved to the translation of a key, see [Arg::Key]
+    pub fn key(key: impl Into<String>) -> Self {
+        Self::Key(key.into())
+    }
+}
+
+impl<T: Display> From<T> for Arg {
+    fn from(value: T) -> Self {
+        Self::Value(value.to_string())
+    }
+}
+
+/// Values that can be passed as interpolation arguments, any [Display] value or an [Arg]
+pub trait ToArg {
+    /// Argument of the value
+    fn to_arg(&self) -> Arg;
+}
+
+impl<T: Display + ?Sized> ToArg for T {
+    fn to_arg(&self) -> Arg {
+        Arg::Value(self.to_string())
+    }
+}
+
+impl ToArg for Arg {
+    fn to_arg(&self) -> Arg {
+        self.clone()
+    }
+}
+
+/// Named interpolation arguments, `(name, value)`
+pub(crate) type NamedArgs<'a> = Vec<(&'a str, String)>;
+
+/// Splits the arguments into the values and the keys that [crate::prelude::I18n] translates before interpolating them
+pub(crate) fn split_args<'a>(args: &[(&'a str, impl ToArg)]) -> (NamedArgs<'a>, NamedArgs<'a>) {
+    let mut values = vec![];
+    let mut keys = vec![];
+    for (name, arg) in args {
+        match arg.to_arg() {
+            Arg::Value(value) => values.push((*name, value)),
+            Arg::Key(key) => keys.push((*name, key)),
+        }
+    }
+    (values, keys)
+}
@@ -0,0 +1,232 @@
+use bevy::{
+    asset::{io::Reader, Asset, AssetLoader, LoadContext},
+    reflect::TypePath,
+    utils::hashbrown::HashMap,
+};
+
+#[cfg(feature = "csv")]
+#[path = "../build/csv.rs"]
+mod csv;
+#[cfg(feature = "fluent")]
+#[path = "../build/fluent.rs"]
+mod fluent;
+#[cfg(feature = "gettext")]
+#[path = "../build/gettext.rs"]
+mod gettext;
+#[path = "../build/locale_path.rs"]
+mod locale_path;
+
+/// Translations of a single locale file, loaded at runtime by the asset server
+///
+/// Maps a locale to its flattened `key -> value` translations,
+/// a file can hold multiple locales when it uses the `_version: 2` format or is a CSV file
+#[derive(Asset, TypePath, Debug, Default)]
+pub(crate) struct LocaleFile(pub(crate) HashMap<String, HashMap<String, String>>);
+
+/// Error returned by the [LocaleFileLoader]
+#[derive(Debug)]
+pub(crate) enum LocaleFileError {
+    Io(std::io::Error),
+    Parse(String),
+}
+
+impl std::fmt::Display for LocaleFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Could not read locale file: {}", err),
+            Self::Parse(err) => write!(f, "Could not parse locale file: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for LocaleFileError {}
+
+impl From<std::io::Error> for LocaleFileError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Asset loader for the locale files, supporting the same formats as `rust-i18n`,
+/// along with the Fluent, gettext and CSV files when their feature is enabled
+pub(crate) struct LocaleFileLoader {
+    /// Folders the locale files are loaded from, the locale folders are relative to them
+    pub(crate) roots: Vec<String>,
+}
+
+impl AssetLoader for LocaleFileLoader {
+    type Asset = LocaleFile;
+    type Settings = ();
+    type Error = LocaleFileError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let path = load_context.path();
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        let relative = self
+            .roots
+            .iter()
+            .find_map(|root| path.strip_prefix(root).ok())
+            .unwrap_or(path);
+        let locale = locale_path::locale_of(relative).to_string();
+        if ext == "csv" {
+            let translations = parse_csv(&bytes).map_err(LocaleFileError::Parse)?;
+            bevy::log::debug!("Loaded locale file: {}", path.display());
+            return Ok(LocaleFile(translations));
+        }
+        if matches!(ext, "ftl" | "po" | "mo") {
+            let table = parse_backend(ext, &bytes).map_err(LocaleFileError::Parse)?;
+            bevy::log::debug!("Loaded locale file: {}", path.display());
+            return Ok(LocaleFile(HashMap::from([(
+                locale,
+                table.into_iter().collect(),
+            )])));
+        }
+        let content =
+            std::str::from_utf8(&bytes).map_err(|err| LocaleFileError::Parse(err.to_string()))?;
+        let value: serde_json::Value =
+            match ext {
+                "yml" | "yaml" => serde_yaml::from_str(content)
+                    .map_err(|err| LocaleFileError::Parse(err.to_string())),
+                "json" => serde_json::from_str(content)
+                    .map_err(|err| LocaleFileError::Parse(err.to_string())),
+                "toml" => {
+                    toml::from_str(content).map_err(|err| LocaleFileError::Parse(err.to_string()))
+                }
+                _ => Err(LocaleFileError::Parse(format!(
+                    "Unsupported extension: {}",
+                    ext
+                ))),
+            }?;
+
+        let mut translations = HashMap::new();
+        if value.get("_version").and_then(|v| v.as_u64()) == Some(2) {
+            if let serde_json::Value::Object(root) = &value {
+                for (key, value) in root.iter().filter(|(key, _)| *key != "_version") {
+                    if let serde_json::Value::Object(locales) = value {
+                        parse_v2(key, locales, &mut translations);
+                    }
+                }
+            }
+        } else {
+            let table: &mut HashMap<String, String> = translations.entry(locale).or_default();
+            flatten("", &value, table);
+            table.remove("_version");
+        }
+        bevy::log::debug!("Loaded locale file: {}", path.display());
+
+        Ok(LocaleFile(translations))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["yml", "yaml", "json", "toml", "ftl", "po", "mo", "csv"]
+    }
+}
+
+/// Parses a CSV file with the columns the build script was configured with, the files have no translations without the `csv` feature
+#[cfg_attr(not(feature = "csv"), allow(unused_variables))]
+fn parse_csv(bytes: &[u8]) -> Result<HashMap<String, HashMap<String, String>>, String> {
+    #[cfg(feature = "csv")]
+    {
+        let content = std::str::from_utf8(bytes).map_err(|err| err.to_string())?;
+        let columns = csv::Columns::new(
+            option_env!("BEVY_I18N_CSV_KEY_COLUMN"),
+            option_env!("BEVY_I18N_CSV_LOCALE_COLUMNS"),
+        );
+        let mut translations: HashMap<String, HashMap<String, String>> = HashMap::new();
+        for (locale, key, value) in csv::parse(content, &columns)? {
+            translations.entry(locale).or_default().insert(key, value);
+        }
+        Ok(translations)
+    }
+    #[cfg(not(feature = "csv"))]
+    {
+        bevy::log::debug!("Skipping a csv locale file, its feature is disabled");
+        Ok(HashMap::new())
+    }
+}
+
+/// Parses a Fluent or gettext file like the build script does, the files of a disabled backend have no translations
+#[cfg_attr(
+    not(all(feature = "fluent", feature = "gettext")),
+    allow(unused_variables)
+)]
+fn parse_backend(ext: &str, bytes: &[u8]) -> Result<Vec<(String, String)>, String> {
+    let utf8 = |bytes| std::str::from_utf8(bytes).map_err(|err| err.to_string());
+    match ext {
+        #[cfg(feature = "fluent")]
+        "ftl" => utf8(bytes).and_then(fluent::parse),
+        #[cfg(feature = "gettext")]
+        "po" => utf8(bytes).and_then(gettext::parse_po),
+        #[cfg(feature = "gettext")]
+        "mo" => gettext::parse_mo(bytes),
+        _ => {
+            bevy::log::debug!("Skipping a {} locale file, its feature is disabled", ext);
+            Ok(vec![])
+        }
+    }
+}
+
+/// Parses a `_version: 2` entry, where the leaves are `locale: value` pairs
+fn parse_v2(
+    key: &str,
+    locales: &serde_json::Map<String, serde_json::Value>,
+    translations: &mut HashMap<String, HashMap<String, String>>,
+) {
+    for (locale, value) in locales {
+        match value {
+            serde_json::Value::String(text) => {
+                translations
+                    .entry(locale.clone())
+                    .or_default()
+                    .insert(key.to_string(), text.clone());
+            }
+            serde_json::Value::Object(nested) => {
+                parse_v2(&format!("{}.{}", key, locale), nested, translations);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Flattens nested objects into dotted keys and lists into indexed keys, `tips.0`, `tips.1`
+fn flatten(prefix: &str, value: &serde_json::Value, table: &mut HashMap<String, String>) {
+    let key = |key: &dyn std::fmt::Display| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", prefix, key)
+        }
+    };
+    match value {
+        serde_json::Value::Object(map) => {
+            for (name, value) in map {
+                flatten(&key(name), value, table);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                flatten(&key(&index), value, table);
+            }
+        }
+        serde_json::Value::String(text) => {
+            table.insert(prefix.to_string(), text.clone());
+        }
+        serde_json::Value::Null => {
+            table.insert(prefix.to_string(), String::new());
+        }
+        other => {
+            table.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Folders of the localized sounds found in the assets folder by the build script, `voice/greeting/fr.ogg`
+
+include!(concat!(env!("OUT_DIR"), "/bevy_simple_i18n_audio.rs"));
+
+/// Asset path of the clip of a sound for a locale, or of its `fallback` clip, if it exists
+pub(crate) fn find(sound: &str, locale: &str) -> Option<String> {
+    let index = AUDIO_FOLDERS
+        .binary_search_by_key(&sound, |(folder, _)| folder)
+        .ok()?;
+    AUDIO_FOLDERS[index]
+        .1
+        .iter()
+        .find(|clip| {
+            clip.rsplit_once('.')
+                .is_some_and(|(stem, _)| stem == locale)
+        })
+        .map(|clip| format!("{}/{}", sound, clip))
+}
@@ -0,0 +1,15 @@
+//! Translations of the Fluent, gettext and CSV files, which `rust-i18n` doesn't support, parsed by the build script
+
+include!(concat!(env!("OUT_DIR"), "/bevy_simple_i18n_backends.rs"));
+
+/// Translation of a key parsed from the Fluent, gettext or CSV files of a locale
+pub(crate) fn translate(locale: &str, key: &str) -> Option<&'static str> {
+    let (_, table) = BACKEND_TRANSLATIONS.iter().find(|(l, _)| *l == locale)?;
+    let index = table.binary_search_by_key(&key, |(k, _)| k).ok()?;
+    Some(table[index].1)
+}
+
+/// Locales that have Fluent, gettext or CSV translations
+pub(crate) fn locales() -> impl Iterator<Item = &'static str> {
+    BACKEND_TRANSLATIONS.iter().map(|(locale, _)| *locale)
+}
@@ -0,0 +1,59 @@
+use bevy::{
+    ecs::{component::Component, reflect::ReflectComponent},
+    reflect::Reflect,
+};
+
+/// Component for spawning localized sound entities that are managed by `bevy_simple_i18n`, e.g. voiced lines
+///
+/// The sound is a folder of the assets with a clip per locale, `voice/greeting/fr.ogg`, and an optional `fallback.ogg`,
+/// the clip is resolved for the locale set by the [crate::resources::I18n] resource, see [crate::resources::I18n::audio_path_in]
+///
+/// It inserts (or updates) the `AudioPlayer` of the entity. Entities that are already playing keep their clip when the locale changes,
+/// so a voice line isn't restarted halfway through
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_simple_i18n::prelude::*;
+/// # let mut world = World::new();
+/// // `voice/greeting/ja.ogg` in `ja`
+/// world.spawn(I18nAudio::new("voice/greeting"));
+///
+/// // With playback settings
+/// world.spawn((I18nAudio::new("ui/confirm"), PlaybackSettings::DESPAWN));
+/// ```
+#[derive(Component, Default, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct I18nAudio {
+    /// Asset path of the folder of the clips
+    pub(crate) sound: String,
+    /// Locale for this specific sound, `None` to use the global locale
+    pub(crate) locale: Option<String>,
+}
+
+impl I18nAudio {
+    /// Creates a new `I18nAudio` component from the asset path of the folder of the clips
+    pub fn new(sound: impl Into<String>) -> Self {
+        Self {
+            sound: sound.into(),
+            locale: None,
+        }
+    }
+
+    /// Pin this specific sound to a locale, it follows the locale of the [crate::resources::I18n] resource by default
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Asset path of the folder of the clips
+    pub fn sound(&self) -> &str {
+        &self.sound
+    }
+
+    /// Locale this sound is pinned to, `None` when it follows the locale of the [crate::resources::I18n] resource
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+}
@@ -18,18 +18,85 @@ use super::I18nText;
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_simple_i18n::prelude::*;
+/// # let mut world = World::new();
 /// world.spawn((I18nText::new("hello"), I18nFont::new("NotoSans")));
+///
+/// // With a style variant, e.g. `NotoSans/ja.bold.ttf`
+/// world.spawn((I18nText::new("hello"), I18nFont::new("NotoSans").with_style(FontStyle::Bold)));
+///
+/// // With a numeric weight, e.g. `NotoSans/ja.600.ttf` or a variable `NotoSans/ja.ttf`
+/// world.spawn((I18nText::new("hello"), I18nFont::new("NotoSans").with_weight(600)));
 /// ```
 #[derive(Default, Reflect, Debug, Clone)]
 #[reflect(Component)]
-pub struct I18nFont(pub(crate) String);
+pub struct I18nFont {
+    /// Font family, the name of its folder
+    pub(crate) family: String,
+    /// Style variant of the font family
+    pub(crate) style: FontStyle,
+}
 
 impl I18nFont {
     /// Creates a new `I18nFont` component from the provided font family
     pub fn new(family: impl Into<String>) -> Self {
-        Self(family.into())
+        Self {
+            family: family.into(),
+            style: FontStyle::default(),
+        }
+    }
+
+    /// Set the style variant of the font family
+    pub fn with_style(mut self, style: FontStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the numeric weight of the font, from `100` (thin) to `900` (black), keeping the italic style
+    ///
+    /// Uses the font of the locale with the nearest weight, see [FontStyle::weighted]
+    pub fn with_weight(mut self, weight: u16) -> Self {
+        self.style = FontStyle::weighted(weight, self.style.is_italic());
+        self
+    }
+
+    /// Font family, the name of its folder
+    pub fn family(&self) -> &str {
+        &self.family
     }
+
+    /// Style variant of the font family
+    pub fn style(&self) -> FontStyle {
+        self.style
+    }
+
+    /// Set the font family of an existing entity, e.g. from a settings screen
+    ///
+    /// The font of the locale for the new family is applied on the next update, without translating the text again
+    pub fn set_family(&mut self, family: impl Into<String>) {
+        self.family = family.into();
+    }
+
+    /// Set the style variant of an existing entity, see [I18nFont::set_family]
+    pub fn set_style(&mut self, style: FontStyle) {
+        self.style = style;
+    }
+}
+
+/// Line height and letter spacing of the locale of an [I18nFont] entity, read from the `scales.toml` file of its family,
+/// `None` to keep the default of the text
+///
+/// Inserted on the text entities whose locale sets either of them. This is data only, the plugin doesn't apply it:
+/// Bevy 0.15 lays out the lines at `1.2` times the font size and has no letter spacing
+#[derive(Component, Default, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct TextSpacing {
+    /// Height of the lines relative to the font size, e.g. `1.5` for Thai
+    pub line_height: Option<f32>,
+    /// Space added between the characters relative to the font size, e.g. `-0.02` for tighter CJK layouts
+    pub letter_spacing: Option<f32>,
 }
 
 impl Component for I18nFont {
@@ -50,9 +117,9 @@ impl Component for I18nFont {
             };
 
             let val = world.get::<Self>(entity).unwrap().clone();
-            let font_handler = font_manager.get(&val.0, locale);
+            let font_handler = font_manager.get(&val.family, locale, val.style);
 
-            debug!("Adding dynamic font: {}", val.0);
+            debug!("Adding dynamic font: {}", val.family);
             if let Some(mut font) = world.get_mut::<TextFont>(entity) {
                 font.font = font_handler;
             } else {
@@ -0,0 +1,61 @@
+use bevy::{
+    ecs::{component::Component, reflect::ReflectComponent},
+    reflect::Reflect,
+};
+
+/// Component for spawning localized image entities that are managed by `bevy_simple_i18n`, e.g. textures with baked-in words
+///
+/// Loads the variant of the image for the locale set by the [crate::resources::I18n] resource,
+/// `images/signpost.png` -> `images/signpost.fr.png`, falling back to the image itself, see [crate::resources::I18n::localized_path_in]
+///
+/// It updates the image of the `ImageNode` or `Sprite` of the entity, and inserts an `ImageNode` when it has neither
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_simple_i18n::prelude::*;
+/// # let mut world = World::new();
+/// // A UI image, `images/tutorial.ja.png` in `ja`
+/// world.spawn(I18nImage::new("images/tutorial.png"));
+///
+/// // A sprite
+/// world.spawn((Sprite::default(), I18nImage::new("images/signpost.png")));
+///
+/// // Pinned to a locale
+/// world.spawn(I18nImage::new("images/logo.png").with_locale("en"));
+/// ```
+#[derive(Component, Default, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct I18nImage {
+    /// Asset path of the image without a locale suffix
+    pub(crate) path: String,
+    /// Locale for this specific image, `None` to use the global locale
+    pub(crate) locale: Option<String>,
+}
+
+impl I18nImage {
+    /// Creates a new `I18nImage` component from the asset path of the image without a locale suffix
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            locale: None,
+        }
+    }
+
+    /// Pin this specific image to a locale, it follows the locale of the [crate::resources::I18n] resource by default
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Asset path of the image without a locale suffix
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Locale this image is pinned to, `None` when it follows the locale of the [crate::resources::I18n] resource
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+}
@@ -5,6 +5,7 @@ use bevy::{
     },
     log::debug,
     reflect::Reflect,
+    text::{Text2d, TextSpan},
     ui::widget::Text,
 };
 use fixed_decimal::FixedDecimal;
@@ -13,13 +14,18 @@ use super::utils;
 
 /// Component for spawning translatable number entities that are managed by `bevy_simple_i18n`
 ///
-/// It automatically inserts (or replaces) a Bevy `Text` component with the localized number
+/// It automatically inserts (or replaces) a Bevy `Text` component with the localized number,
+/// if the entity already has a `Text2d` or `TextSpan` component, that one is updated instead
 ///
-/// Updates automatically whenever the locale is changed using the [crate::resources::I18n] resource
+/// Updates automatically whenever the locale is changed using the [crate::resources::I18n] resource,
+/// or when the number is changed with [I18nNumber::set_number]
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_simple_i18n::prelude::*;
+/// # let mut world = World::new();
 /// // Basic usage
 /// world.spawn(I18nNumber::new(200.40));
 ///
@@ -28,7 +34,7 @@ use super::utils;
 /// // does not update when the locale is changed
 /// world.spawn(I18nNumber::new(12051).with_locale("ja"));
 /// ```
-#[derive(Default, Reflect, Debug, Clone)]
+#[derive(Default, Reflect, Debug, Clone, PartialEq)]
 #[reflect(Component)]
 pub struct I18nNumber {
     #[reflect(ignore)]
@@ -52,9 +58,13 @@ impl I18nNumber {
         self
     }
 
+    /// Set the number of an existing entity, which is localized again on the next update
+    pub fn set_number(&mut self, number: impl Into<f64>) {
+        self.fixed_decimal = utils::f64_to_fd(number.into());
+    }
+
     pub(crate) fn translate(&self) -> String {
-        utils::get_formatter(&self.locale, &self.fixed_decimal)
-            .format_to_string(&self.fixed_decimal)
+        utils::format_decimal(&self.locale, &self.fixed_decimal, &self.fixed_decimal)
     }
 }
 
@@ -67,6 +77,10 @@ impl Component for I18nNumber {
             debug!("Adding i18n number: {}", val.fixed_decimal);
             if let Some(mut text) = world.get_mut::<Text>(entity) {
                 **text = val.translate();
+            } else if let Some(mut text) = world.get_mut::<TextSpan>(entity) {
+                **text = val.translate();
+            } else if let Some(mut text) = world.get_mut::<Text2d>(entity) {
+                **text = val.translate();
             } else {
                 world
                     .commands()
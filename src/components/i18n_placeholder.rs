@@ -0,0 +1,96 @@
+use std::ops::{Deref, DerefMut};
+
+use bevy::{
+    ecs::{component::Component, reflect::ReflectComponent},
+    reflect::Reflect,
+};
+
+use super::I18nText;
+
+/// Component for localizing the placeholder text of an input widget, e.g. `Enter your name…`
+///
+/// Translated like an [I18nText], including its arguments, count and locale, and written to the [TextPlaceholder]
+/// component of the entity whenever the locale, the fonts or the component itself change.
+/// The font of the locale is set on the `TextFont` of the widget when the entity has a [crate::components::I18nFont]
+///
+/// The widget has to be registered with [crate::prelude::I18nPlugin::with_placeholder_widget]
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_simple_i18n::prelude::*;
+/// # #[derive(Component, Default)]
+/// # struct TextInput { placeholder: String }
+/// # let mut world = World::new();
+/// world.spawn((TextInput::default(), I18nPlaceholder::new("form.name")));
+///
+/// // With the options of an `I18nText`
+/// world.spawn((
+///     TextInput::default(),
+///     I18nPlaceholder::from(I18nText::new("form.search").with_arg("scope", "items")),
+/// ));
+/// ```
+#[derive(Component, Default, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct I18nPlaceholder(pub I18nText);
+
+impl I18nPlaceholder {
+    /// Creates a new `I18nPlaceholder` component with the provided translation key
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(I18nText::new(key))
+    }
+}
+
+impl From<I18nText> for I18nPlaceholder {
+    fn from(text: I18nText) -> Self {
+        Self(text)
+    }
+}
+
+impl Deref for I18nPlaceholder {
+    type Target = I18nText;
+
+    fn deref(&self) -> &I18nText {
+        &self.0
+    }
+}
+
+impl DerefMut for I18nPlaceholder {
+    fn deref_mut(&mut self) -> &mut I18nText {
+        &mut self.0
+    }
+}
+
+/// Input widget component whose placeholder text is localized by an [I18nPlaceholder]
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_simple_i18n::prelude::*;
+/// #[derive(Component, Default)]
+/// struct TextInput {
+///     value: String,
+///     placeholder: String,
+/// }
+///
+/// impl TextPlaceholder for TextInput {
+///     fn placeholder(&self) -> &str {
+///         &self.placeholder
+///     }
+///
+///     fn set_placeholder(&mut self, placeholder: String) {
+///         self.placeholder = placeholder;
+///     }
+/// }
+///
+/// App::new().add_plugins(I18nPlugin::default().with_placeholder_widget::<TextInput>());
+/// ```
+pub trait TextPlaceholder: Component {
+    /// Placeholder text currently displayed by the widget
+    fn placeholder(&self) -> &str;
+
+    /// Replace the placeholder text of the widget, only called when the translation changed
+    fn set_placeholder(&mut self, placeholder: String);
+}
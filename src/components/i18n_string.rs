@@ -0,0 +1,103 @@
+use std::ops::{Deref, DerefMut};
+
+#[cfg(feature = "window")]
+use bevy::window::Window;
+use bevy::{
+    ecs::{component::Component, reflect::ReflectComponent},
+    reflect::Reflect,
+};
+
+use super::I18nText;
+
+/// Component for localizing a string field of another component of the entity, e.g. the title of its `Window`
+///
+/// Translated like an [I18nText], including its arguments, count and locale, and written to the [StringTarget]
+/// component of the entity whenever the locale or the component itself change. Markup tags are stripped
+///
+/// `Window` is a target out of the box with the `window` feature, the other targets have to be registered with
+/// [crate::prelude::I18nPlugin::with_string_target]
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::{prelude::*, window::PrimaryWindow};
+/// # use bevy_simple_i18n::prelude::*;
+/// fn localize_title(mut commands: Commands, window: Query<Entity, With<PrimaryWindow>>) {
+///     if let Ok(window) = window.get_single() {
+///         commands.entity(window).insert(I18nString::new("app.title"));
+///     }
+/// }
+/// ```
+#[derive(Component, Default, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct I18nString(pub I18nText);
+
+impl I18nString {
+    /// Creates a new `I18nString` component with the provided translation key
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(I18nText::new(key))
+    }
+}
+
+impl From<I18nText> for I18nString {
+    fn from(text: I18nText) -> Self {
+        Self(text)
+    }
+}
+
+impl Deref for I18nString {
+    type Target = I18nText;
+
+    fn deref(&self) -> &I18nText {
+        &self.0
+    }
+}
+
+impl DerefMut for I18nString {
+    fn deref_mut(&mut self) -> &mut I18nText {
+        &mut self.0
+    }
+}
+
+/// Component with a string field that's localized by an [I18nString], e.g. the title of a `Window`
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_simple_i18n::prelude::*;
+/// #[derive(Component, Default)]
+/// struct Notification {
+///     body: String,
+/// }
+///
+/// impl StringTarget for Notification {
+///     fn text(&self) -> &str {
+///         &self.body
+///     }
+///
+///     fn set_text(&mut self, text: String) {
+///         self.body = text;
+///     }
+/// }
+///
+/// App::new().add_plugins(I18nPlugin::default().with_string_target::<Notification>());
+/// ```
+pub trait StringTarget: Component {
+    /// String currently held by the component
+    fn text(&self) -> &str;
+
+    /// Replace the string of the component, only called when the translation changed
+    fn set_text(&mut self, text: String);
+}
+
+#[cfg(feature = "window")]
+impl StringTarget for Window {
+    fn text(&self) -> &str {
+        &self.title
+    }
+
+    fn set_text(&mut self, text: String) {
+        self.title = text;
+    }
+}
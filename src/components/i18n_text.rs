@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use bevy::{
     ecs::{
         component::{Component, ComponentHooks, StorageType},
@@ -5,18 +7,26 @@ use bevy::{
     },
     log::debug,
     reflect::Reflect,
+    text::{Text2d, TextSpan},
     ui::widget::Text,
 };
-use rust_i18n::t;
 
-#[cfg(feature = "numbers")]
+#[cfg(feature = "fonts")]
+use crate::resources::FontsLoading;
+use crate::resources::{I18n, TranslateOptions};
+
+#[cfg(any(feature = "numbers", feature = "plurals"))]
 use fixed_decimal::FixedDecimal;
 
 /// Component for spawning translatable text entities that are managed by `bevy_simple_i18n`
 ///
-/// It automatically inserts (or replaces) a Bevy `Text` component with the translated text using the provided key
+/// It automatically inserts (or replaces) a Bevy `Text` component with the translated text using the provided key,
+/// if the entity already has a `Text2d` or `TextSpan` component, that one is updated instead
+///
+/// Attaching it to a `TextSpan` child only translates that span, leaving its siblings untouched
 ///
-/// Updates automatically whenever the locale is changed using the [crate::resources::I18n] resource
+/// Updates automatically whenever the locale is changed using the [crate::resources::I18n] resource,
+/// or when the component itself is changed, e.g. by [I18nText::set_arg]
 ///
 /// # Example
 ///
@@ -24,23 +34,61 @@ use fixed_decimal::FixedDecimal;
 /// // en.json
 /// {
 ///     "hello": "Hello, World!",
-///     "greet": "Hello, %{name}!"
+///     "greet": "Hello, {name}!"
 /// }
 /// ```
 ///
 /// ```
+/// # use bevy::prelude::*;
+/// # use bevy_simple_i18n::prelude::*;
+/// # let mut world = World::new();
+/// # world.init_resource::<FontManager>();
 /// // Basic usage
 /// world.spawn(I18nText::new("hello"));
 ///
 /// // With interpolation arguments
 /// world.spawn(I18nText::new("greet").with_arg("name", "Bevy User"));
 ///
-/// // With forced locale
+/// // With a count that selects the plural form
+/// world.spawn(I18nText::new("inventory.items").with_count(3));
+///
+/// // Translating a single span of a composite text, `Press [E] to open`
+/// world.spawn(Text::default()).with_children(|parent| {
+///     parent.spawn((TextSpan::default(), I18nText::new("press")));
+///     parent.spawn(TextSpan::new(" [E] "));
+///     parent.spawn((TextSpan::default(), I18nText::new("to_open")));
+/// });
+///
+/// // With a context that selects a variant, `player.joined.female`
+/// world.spawn(I18nText::new("player.joined").with_context("female"));
+///
+/// // With a default text while the key isn't translated yet
+/// world.spawn(I18nText::new("feature.title").with_default("New Feature"));
+///
+/// // With markup tags rendered as styled spans, `Press <b>E</b> to open`
+/// world.spawn((I18nText::new("press_to_open").with_markup(), I18nFont::new("NotoSans")));
+///
+/// // Pinned to a locale
 /// // overrides the global
 /// // does not update when the locale is changed
-/// world.spawn(I18nText::new("hello").with_locale("ja"));
+/// world.spawn(I18nText::pinned("en", "legal.notice"));
+///
+/// // Bound to live data, `HP: {current}/{max}`, only re-translated when the health changes
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// fn update_health_label(
+///     health: Query<&Health, Changed<Health>>,
+///     mut label: Query<&mut I18nText>,
+/// ) {
+///     for health in &health {
+///         for mut text in &mut label {
+///             text.set_arg("current", health.0);
+///         }
+///     }
+/// }
 /// ```
-#[derive(Default, Reflect, Debug, Clone)]
+#[derive(Default, Reflect, Debug, Clone, PartialEq)]
 #[reflect(Component)]
 pub struct I18nText {
     /// Translation key for i18n
@@ -49,6 +97,18 @@ pub struct I18nText {
     args: Vec<(String, InterpolationType)>,
     /// Locale for this specific translation, `None` to use the global locale
     pub(crate) locale: Option<String>,
+    /// Context used to select a variant of the translation, e.g. `female` or `formal`
+    context: Option<String>,
+    /// Text displayed with the fallback font while the font of the locale is loading, `None` to display the translation
+    pub(crate) loading_placeholder: Option<String>,
+    /// Whether the `<b>`, `<i>` and `<color>` tags of the translation are rendered as styled spans
+    pub(crate) markup: bool,
+    /// Text used when the key is missing in the locale and all of its fallbacks
+    default: Option<String>,
+    #[cfg(feature = "plurals")]
+    #[reflect(ignore)]
+    /// Count used to select the plural form of the translation
+    count: Option<FixedDecimal>,
 }
 
 impl I18nText {
@@ -58,61 +118,223 @@ impl I18nText {
             key: str.into(),
             args: vec![],
             locale: None,
+            context: None,
+            loading_placeholder: None,
+            default: None,
+            markup: false,
+            #[cfg(feature = "plurals")]
+            count: None,
         }
     }
 
-    /// Set the locale for this specific translation
+    /// Creates a new `I18nText` component pinned to a locale, see [I18nText::with_locale]
+    pub fn pinned(locale: impl Into<String>, key: impl Into<String>) -> Self {
+        Self::new(key).with_locale(locale)
+    }
+
+    /// Pin this specific translation, and its [crate::components::I18nFont], to a locale
+    ///
+    /// Pinned texts ignore the locale of the [I18n] resource, e.g. a legal notice that's always in English,
+    /// they follow it by default
     pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
         self.locale = Some(locale.into());
         self
     }
 
+    /// Locale this translation is pinned to, `None` when it follows the locale of the [I18n] resource
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Set the context used to select a variant of the translation key, e.g. gendered or formal strings
+    ///
+    /// The variant is looked up as a nested key, `key.female`, falling back to the key itself and then to the neutral `key.other`.
+    /// Composes with [I18nText::with_count], the context is resolved first: `key.female.one` -> `key.female.other` -> `key.female` -> `key.one` -> `key.other` -> `key`
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    #[cfg(feature = "fonts")]
+    /// Set the text displayed while the [crate::components::I18nFont] of the locale is still loading, e.g. `…`
+    ///
+    /// It's rendered with the fallback font of the family and replaced by the translation once the font is loaded
+    pub fn loading_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.loading_placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set the text used when the key is missing in the locale and all of its fallbacks,
+    /// instead of the [crate::resources::MissingKeyBehavior] of the [I18n] resource
+    ///
+    /// Useful for keys that are optional or not translated yet, a real translation always takes precedence
+    ///
+    /// The default is interpolated like the translations
+    pub fn with_default(mut self, default: impl Into<String>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Render the `<b>`, `<i>` and `<color=#rrggbb>` tags of the translation as styled child spans,
+    /// e.g. `Press <b>E</b> to <color=#ff0>open</color>`
+    ///
+    /// Bold and italic use the font styles of the [crate::components::I18nFont] of the entity,
+    /// translations without markup are written as-is
+    pub fn with_markup(mut self) -> Self {
+        self.markup = true;
+        self
+    }
+
     /// Add a standard string interpolation argument to the translation key
     ///
-    /// This method can be called as many times as needed
+    /// The argument replaces the `{key}` (or `%{key}`) placeholders of the translation,
+    /// placeholders without a matching argument are left as-is
+    ///
+    /// This method can be called as many times as needed, setting the same key again replaces its value
     pub fn with_arg(mut self, key: impl Into<String>, value: impl ToString) -> Self {
-        self.args
-            .push((key.into(), InterpolationType::String(value.to_string())));
+        self.set_arg(key, value);
         self
     }
 
+    /// Set a standard string interpolation argument of an existing text, see [I18nText::with_arg]
+    ///
+    /// Changing the component re-translates the text on the next update,
+    /// so the source of the value should be filtered with `Changed` to not re-translate it every frame
+    pub fn set_arg(&mut self, key: impl Into<String>, value: impl ToString) {
+        self.insert_arg(key.into(), InterpolationType::String(value.to_string()));
+    }
+
+    /// Add an interpolation argument replaced by the translation of another key in the same locale,
+    /// e.g. the localized word of an enum variant, `with_key_arg("type", "damage.fire")`
+    ///
+    /// Select expressions choose their branch from the key itself, see [crate::prelude::Arg]
+    pub fn with_key_arg(mut self, key: impl Into<String>, arg_key: impl Into<String>) -> Self {
+        self.set_key_arg(key, arg_key);
+        self
+    }
+
+    /// Set an interpolation argument of an existing text to the translation of another key, see [I18nText::with_key_arg]
+    pub fn set_key_arg(&mut self, key: impl Into<String>, arg_key: impl Into<String>) {
+        self.insert_arg(key.into(), InterpolationType::Key(arg_key.into()));
+    }
+
     #[cfg(feature = "numbers")]
     /// Add a number interpolation argument to the translation key
     ///
     /// This method can be called as many times as needed
     pub fn with_num_arg(mut self, key: impl Into<String>, value: impl Into<f64>) -> Self {
-        self.args.push((
+        self.set_num_arg(key, value);
+        self
+    }
+
+    #[cfg(feature = "numbers")]
+    /// Set a number interpolation argument of an existing text, see [I18nText::set_arg]
+    pub fn set_num_arg(&mut self, key: impl Into<String>, value: impl Into<f64>) {
+        self.insert_arg(
             key.into(),
             InterpolationType::Number(super::utils::f64_to_fd(value.into())),
-        ));
+        );
+    }
+
+    fn insert_arg(&mut self, key: String, value: InterpolationType) {
+        if let Some((_, existing)) = self.args.iter_mut().find(|(k, _)| *k == key) {
+            *existing = value;
+        } else {
+            self.args.push((key, value));
+        }
+    }
+
+    #[cfg(feature = "plurals")]
+    /// Set the count used to select the plural form of the translation key
+    ///
+    /// The plural form is looked up as a nested key using the CLDR plural category of the locale,
+    /// falling back to `other` and then to the key itself, e.g. `inventory.items.few` -> `inventory.items.other` -> `inventory.items`
+    ///
+    /// The count is also available to the translation as the `count` interpolation argument
+    pub fn with_count(mut self, count: impl Into<f64>) -> Self {
+        self.set_count(count);
         self
     }
 
-    /// Internal method that wraps the `rust_i18n::t!` macro
-    pub(crate) fn translate(&self) -> String {
+    #[cfg(feature = "plurals")]
+    /// Set the count of an existing text, see [I18nText::with_count] and [I18nText::set_arg]
+    pub fn set_count(&mut self, count: impl Into<f64>) {
+        self.count = Some(super::utils::f64_to_fd(count.into()));
+    }
+
+    /// Translation of the text in its plain and ruby annotated segments, see [I18n::t_ruby]
+    ///
+    /// The text itself only renders the base of the annotations
+    pub fn ruby(&self, i18n: &I18n) -> Vec<crate::ruby::RubySegment> {
+        crate::ruby::parse_ruby(&self.annotated(i18n))
+    }
+
+    /// Internal method that looks up the translation through the [I18n] fallback chain
+    pub(crate) fn translate<'a>(&'a self, i18n: &'a I18n) -> Cow<'a, str> {
+        crate::ruby::strip_ruby(self.annotated(i18n))
+    }
+
+    /// Translation of the text along with its ruby annotations
+    fn annotated<'a>(&'a self, i18n: &'a I18n) -> Cow<'a, str> {
+        if i18n.show_keys() {
+            return Cow::Owned(match self.locale.as_ref() {
+                Some(locale) => format!("«{}@{}»", self.key, locale),
+                None => format!("«{}»", self.key),
+            });
+        }
         #[cfg(feature = "numbers")]
         let fdf = super::utils::get_formatter(&self.locale, &self.key);
 
-        let (patterns, values): (Vec<&str>, Vec<String>) = self
+        let args: Vec<(&str, String)> = self
             .args
             .iter()
-            .map(|(k, interpolation_type)| {
+            .filter_map(|(k, interpolation_type)| {
                 let value = match interpolation_type {
                     InterpolationType::String(v) => v.clone(),
                     #[cfg(feature = "numbers")]
-                    InterpolationType::Number(v) => fdf.format_to_string(v),
+                    InterpolationType::Number(v) => fdf
+                        .as_ref()
+                        .map_or_else(|| v.to_string(), |fdf| fdf.format_to_string(v)),
+                    InterpolationType::Key(_) => return None,
                 };
-                (k.as_str(), value)
+                Some((k.as_str(), value))
             })
-            .unzip();
-        let translated = if let Some(locale) = self.locale.as_ref() {
-            t!(&self.key, locale = locale)
-        } else {
-            t!(&self.key)
-        };
+            .collect();
+        let keys: Vec<(&str, String)> = self
+            .args
+            .iter()
+            .filter_map(|(k, interpolation_type)| match interpolation_type {
+                InterpolationType::Key(key) => Some((k.as_str(), key.clone())),
+                _ => None,
+            })
+            .collect();
+        #[cfg(feature = "numbers")]
+        let numbers: Vec<(&str, f64)> = self
+            .args
+            .iter()
+            .filter_map(|(k, interpolation_type)| match interpolation_type {
+                InterpolationType::Number(v) => Some((k.as_str(), v.to_string().parse().ok()?)),
+                _ => None,
+            })
+            .collect();
+        #[cfg(not(feature = "numbers"))]
+        let numbers = vec![];
+        let current = rust_i18n::locale();
+        let locale = self.locale.as_deref().unwrap_or(&*current);
 
-        let val = rust_i18n::replace_patterns(&translated, patterns.as_slice(), values.as_slice());
-        val
+        i18n.translate(
+            locale,
+            &self.key,
+            TranslateOptions {
+                args,
+                numbers,
+                keys: &keys,
+                context: self.context.as_deref(),
+                default: self.default.as_deref(),
+                #[cfg(feature = "plurals")]
+                count: self.count.as_ref(),
+            },
+        )
     }
 }
 
@@ -122,22 +344,59 @@ impl Component for I18nText {
     fn register_component_hooks(_hooks: &mut ComponentHooks) {
         _hooks.on_add(|mut world, entity, _| {
             let val = world.get::<Self>(entity).unwrap().clone();
+            if !world.contains_resource::<I18n>() {
+                debug!(
+                    "I18n resource is not initialized, not translating {} yet",
+                    val.key
+                );
+                return;
+            }
             debug!("Adding i18n text: {}", val.key);
+            let has_text = world.get::<Text>(entity).is_some()
+                || world.get::<TextSpan>(entity).is_some()
+                || world.get::<Text2d>(entity).is_some();
+            // The observers translate the entity once it has a text component
+            if cfg!(feature = "observers") {
+                if !has_text {
+                    world.commands().entity(entity).insert(Text::default());
+                }
+                return;
+            }
+            let translated = match val.loading_placeholder.as_ref() {
+                // The font is applied by the plugin systems, which replace the placeholder once it's loaded
+                #[cfg(feature = "fonts")]
+                Some(placeholder) if world.contains_resource::<FontsLoading>() => {
+                    placeholder.clone()
+                }
+                _ => val.translate(world.resource::<I18n>()).into_owned(),
+            };
+            // The styled spans are spawned by the plugin systems
+            let translated = if val.markup {
+                crate::markup::strip_markup(&translated)
+            } else {
+                translated
+            };
             if let Some(mut text) = world.get_mut::<Text>(entity) {
-                **text = val.translate();
+                **text = translated;
+            } else if let Some(mut text) = world.get_mut::<TextSpan>(entity) {
+                **text = translated;
+            } else if let Some(mut text) = world.get_mut::<Text2d>(entity) {
+                **text = translated;
             } else {
                 world
                     .commands()
                     .entity(entity)
-                    .insert(Text::new(val.translate()));
+                    .insert(Text::new(translated));
             }
         });
     }
 }
 
-#[derive(Reflect, Debug, Clone)]
+#[derive(Reflect, Debug, Clone, PartialEq)]
 enum InterpolationType {
     String(String),
     #[cfg(feature = "numbers")]
     Number(#[reflect(ignore)] FixedDecimal),
+    /// Key translated in the locale of the text
+    Key(String),
 }
@@ -0,0 +1,35 @@
+use bevy::{
+    ecs::{component::Component, reflect::ReflectComponent},
+    reflect::Reflect,
+};
+
+/// Marks the root of a UI subtree whose layout is mirrored while the active locale is right-to-left,
+/// see [crate::resources::LayoutDirection]
+///
+/// The `Node` of the root and of its descendants are flipped horizontally, rows are reversed, the horizontal
+/// alignments of the columns and the grids swap their start and end, and the left and right positions,
+/// margins, paddings and borders are swapped. They're flipped back when switching to a left-to-right locale,
+/// so the layout of the nodes is the left-to-right one
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_simple_i18n::prelude::*;
+/// # let mut world = World::new();
+/// world
+///     .spawn((Node::default(), MirrorLayout))
+///     .with_children(|menu| {
+///         menu.spawn(I18nText::new("menu.start"));
+///         // The arrow already points the right way
+///         menu.spawn((ImageNode::default(), NoMirror));
+///     });
+/// ```
+#[derive(Component, Default, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct MirrorLayout;
+
+/// Keeps an entity of a [MirrorLayout] subtree and its descendants from being mirrored, e.g. icons or media controls
+#[derive(Component, Default, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct NoMirror;
@@ -1,10 +1,24 @@
+#[cfg(feature = "audio")]
+mod i18n_audio;
+#[cfg(feature = "fonts")]
 mod i18n_font;
+mod i18n_image;
 #[cfg(feature = "numbers")]
 mod i18n_number;
+mod i18n_placeholder;
+mod i18n_string;
 mod i18n_text;
-mod utils;
+mod mirror_layout;
+pub(crate) mod utils;
 
+#[cfg(feature = "audio")]
+pub use i18n_audio::*;
+#[cfg(feature = "fonts")]
 pub use i18n_font::*;
+pub use i18n_image::*;
 #[cfg(feature = "numbers")]
 pub use i18n_number::*;
+pub use i18n_placeholder::*;
+pub use i18n_string::*;
 pub use i18n_text::*;
+pub use mirror_layout::*;
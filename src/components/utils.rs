@@ -1,38 +1,363 @@
-#[cfg(feature = "numbers")]
-pub(super) fn f64_to_fd(value: f64) -> fixed_decimal::FixedDecimal {
+use std::borrow::Cow;
+
+use crate::resources::{Delimiters, TextDirection};
+
+/// Unicode RIGHT-TO-LEFT MARK, invisible character with a strong RTL direction
+pub(crate) const RLM: char = '\u{200F}';
+
+/// Replaces the `{name}` and `%{name}` placeholders of a translation template with the matching arguments,
+/// or the placeholders of the custom [Delimiters]
+///
+/// Arguments that don't appear in the template are ignored,
+/// placeholders without a matching argument are left as-is and logged at debug level.
+/// The backslash of an escaped opening delimiter is removed, `\{name}` -> `{name}`
+///
+/// A placeholder can format a number with a spec after a colon, see [format_spec],
+/// the raw value of the number argument is used when provided, otherwise its string value is parsed
+///
+/// The template is returned as-is, without allocating, when there are no arguments or escaped delimiters to replace
+pub(crate) fn interpolate<'a>(
+    template: Cow<'a, str>,
+    args: &[(&str, String)],
+    numbers: &[(&str, f64)],
+    locale: &str,
+    delimiters: &Delimiters,
+) -> Cow<'a, str> {
+    let (open, close) = (delimiters.open(), delimiters.close());
+    let escaped = |template: &str| {
+        template
+            .match_indices(open)
+            .any(|(index, _)| template[..index].ends_with('\\'))
+    };
+    if !template.contains(open) || args.is_empty() && !escaped(&template) {
+        return template;
+    }
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template.as_ref();
+    while let Some(start) = rest.find(open) {
+        if rest[..start].ends_with('\\') {
+            output.push_str(&rest[..start - 1]);
+            output.push_str(open);
+            rest = &rest[start + open.len()..];
+            continue;
+        }
+        let name_start = start + open.len();
+        let Some(len) = rest[name_start..].find(close) else {
+            break;
+        };
+        let end = name_start + len;
+        // Opening delimiter found before the closing one, move on to it
+        if let Some(next) = rest[name_start..end].find(open) {
+            output.push_str(&rest[..name_start + next]);
+            rest = &rest[name_start + next..];
+            continue;
+        }
+        let placeholder = &rest[name_start..end];
+        let (name, spec) = match placeholder.split_once(':') {
+            Some((name, spec)) => (name, Some(spec)),
+            None => (placeholder, None),
+        };
+        let prefix = if rest[..start].ends_with('%') {
+            start - 1
+        } else {
+            start
+        };
+        match args.iter().find(|(arg, _)| *arg == name) {
+            Some((_, value)) => {
+                output.push_str(&rest[..prefix]);
+                let number = numbers
+                    .iter()
+                    .find(|(arg, _)| *arg == name)
+                    .map(|(_, number)| *number)
+                    .or_else(|| value.parse().ok());
+                match spec.map(|spec| (spec, number.and_then(|n| format_spec(locale, n, spec)))) {
+                    Some((_, Some(formatted))) => output.push_str(&formatted),
+                    Some((spec, None)) => {
+                        bevy::log::warn!(
+                            "Invalid format spec {} for argument {}, using its default format",
+                            spec,
+                            name
+                        );
+                        output.push_str(value);
+                    }
+                    None => output.push_str(value),
+                }
+            }
+            None => {
+                bevy::log::debug!(
+                    "No argument provided for placeholder {}{}{}",
+                    open,
+                    name,
+                    close
+                );
+                output.push_str(&rest[..end + close.len()]);
+            }
+        }
+        rest = &rest[end + close.len()..];
+    }
+    output.push_str(rest);
+    Cow::Owned(output)
+}
+
+/// Formats a number with the format spec of a placeholder, with the separators of the locale:
+///
+/// - `0.0` or `.1`: one fraction digit, `{damage:0.0}` -> `12.5`
+/// - `000` or `03`: at least three integer digits, `{level:03}` -> `007`
+/// - `03.1`: both, `007.5`
+///
+/// The number is rounded half away from zero, `None` when the spec is invalid
+#[cfg_attr(not(feature = "numbers"), allow(unused_variables))]
+pub(crate) fn format_spec(locale: &str, number: f64, spec: &str) -> Option<String> {
+    let (integer, fraction) = spec.split_once('.').unwrap_or((spec, ""));
+    let zeros = |part: &str| part.bytes().all(|b| b == b'0');
+    let integer_digits = if zeros(integer) {
+        integer.len()
+    } else {
+        integer.strip_prefix('0')?.parse().ok()?
+    };
+    let fraction_digits = if zeros(fraction) {
+        fraction.len()
+    } else {
+        fraction.parse().ok()?
+    };
+    if !number.is_finite() || integer_digits > 32 || fraction_digits > 32 {
+        return None;
+    }
+
+    #[cfg(feature = "numbers")]
+    {
+        let mut fixed_decimal = f64_to_fd(number);
+        fixed_decimal.half_expand(-(fraction_digits as i16));
+        fixed_decimal.pad_end(-(fraction_digits as i16));
+        fixed_decimal.pad_start(integer_digits as i16);
+        Some(format_decimal(
+            &Some(locale.to_string()),
+            &fixed_decimal,
+            number,
+        ))
+    }
+    #[cfg(not(feature = "numbers"))]
+    {
+        let width = integer_digits
+            + fraction_digits
+            + usize::from(fraction_digits > 0)
+            + usize::from(number < 0.0);
+        Some(format!("{:0width$.fraction_digits$}", number))
+    }
+}
+
+/// Forces the base direction of every line of a translation for right-to-left locales
+///
+/// Bevy's text shaping already runs the Unicode Bidi algorithm to reorder the glyphs into display order,
+/// but it resolves the base direction of each line from its first strong character,
+/// so an Arabic line starting with a number or a latin word ends up with its punctuation on the wrong side.
+/// Prefixing the lines with a RIGHT-TO-LEFT MARK makes the whole line resolve as RTL
+pub(crate) fn apply_direction<'a>(locale: &str, text: Cow<'a, str>) -> Cow<'a, str> {
+    if !TextDirection::of(locale).is_rtl() || text.split('\n').all(|line| line.starts_with(RLM)) {
+        return text;
+    }
+    Cow::Owned(
+        text.split('\n')
+            .map(|line| {
+                if line.starts_with(RLM) {
+                    line.to_string()
+                } else {
+                    format!("{}{}", RLM, line)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+#[cfg(any(feature = "numbers", feature = "plurals"))]
+pub(crate) fn f64_to_fd(value: f64) -> fixed_decimal::FixedDecimal {
     fixed_decimal::FixedDecimal::try_from_f64(value, fixed_decimal::FloatPrecision::Floating)
-        .expect(format!("Failed to parse FixedDecimal from f64: {}", value).as_str())
+        .unwrap_or_else(|_| panic!("Failed to parse FixedDecimal from f64: {}", value))
 }
 
-#[cfg(feature = "numbers")]
-pub(super) fn resolve_locale(locale: &Option<String>, label: impl ToString) -> icu_locid::Locale {
+/// Parses the locale, or the current one of `rust_i18n`, `None` with a warning when it's invalid
+#[cfg(any(feature = "numbers", feature = "plurals"))]
+pub(crate) fn resolve_locale(
+    locale: &Option<String>,
+    label: impl ToString,
+) -> Option<icu_locid::Locale> {
     let string_locale = locale
         .clone()
         .unwrap_or_else(|| rust_i18n::locale().to_string());
 
-    string_locale.parse().expect(
-        format!(
-            "Invalid locale: {} for key: {}",
-            string_locale,
-            label.to_string()
-        )
-        .as_str(),
-    )
+    string_locale
+        .parse()
+        .map_err(|err| {
+            bevy::log::warn!(
+                "Invalid locale {} for {}: {}",
+                string_locale,
+                label.to_string(),
+                err
+            );
+        })
+        .ok()
 }
 
+/// Number formatter of the locale, `None` with a warning when the locale is invalid or unsupported
 #[cfg(feature = "numbers")]
-pub(super) fn get_formatter(
+pub(crate) fn get_formatter(
     locale: &Option<String>,
     label: impl ToString,
-) -> icu_decimal::FixedDecimalFormatter {
+) -> Option<icu_decimal::FixedDecimalFormatter> {
     let label_string = label.to_string();
-    let locale = resolve_locale(locale, label);
-    let locale_string = locale.to_string();
-    icu_decimal::FixedDecimalFormatter::try_new(&locale.into(), Default::default()).expect(
-        format!(
-            "Failed to create FixedDecimalFormatter for number: {} with locale: {}",
-            label_string, locale_string,
-        )
-        .as_str(),
+    let locale = resolve_locale(locale, label)?;
+    icu_decimal::FixedDecimalFormatter::try_new(&locale.clone().into(), Default::default())
+        .map_err(|err| {
+            bevy::log::warn!(
+                "Failed to create the number formatter for {} with locale {}: {}",
+                label_string,
+                locale,
+                err
+            );
+        })
+        .ok()
+}
+
+/// Formats a number with the separators of the locale, unformatted when the locale has no formatter
+#[cfg(feature = "numbers")]
+pub(crate) fn format_decimal(
+    locale: &Option<String>,
+    number: &fixed_decimal::FixedDecimal,
+    label: impl ToString,
+) -> String {
+    get_formatter(locale, label).map_or_else(
+        || number.to_string(),
+        |formatter| formatter.format_to_string(number),
     )
 }
+
+#[cfg(feature = "plurals")]
+pub(crate) fn plural_category(
+    locale: &Option<String>,
+    count: &fixed_decimal::FixedDecimal,
+    label: impl ToString,
+) -> &'static str {
+    use icu_plurals::{PluralCategory, PluralRules};
+
+    let Some(locale) = resolve_locale(locale, label) else {
+        return "other";
+    };
+    let category = match PluralRules::try_new_cardinal(&locale.clone().into()) {
+        Ok(rules) => rules.category_for(count),
+        Err(err) => {
+            bevy::log::warn!("Failed to load plural rules for {}: {}", locale, err);
+            PluralCategory::Other
+        }
+    };
+    match category {
+        PluralCategory::Zero => "zero",
+        PluralCategory::One => "one",
+        PluralCategory::Two => "two",
+        PluralCategory::Few => "few",
+        PluralCategory::Many => "many",
+        PluralCategory::Other => "other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interpolate_str(template: &str, args: &[(&str, String)], delimiters: &Delimiters) -> String {
+        interpolate(Cow::Borrowed(template), args, &[], "en", delimiters).into_owned()
+    }
+
+    #[test]
+    fn interpolates_braces_and_percent_placeholders() {
+        let args = [("name", "Bevy".to_string()), ("count", "3".to_string())];
+        assert_eq!(
+            interpolate_str("Hello {name}, %{count} new", &args, &Delimiters::default()),
+            "Hello Bevy, 3 new"
+        );
+        assert_eq!(
+            interpolate_str("{missing} {name}", &args, &Delimiters::default()),
+            "{missing} Bevy"
+        );
+        assert_eq!(
+            interpolate_str("{{name}} {name", &args, &Delimiters::default()),
+            "{Bevy} {name"
+        );
+    }
+
+    #[test]
+    fn escaped_delimiters_are_kept_without_their_backslash() {
+        let args = [("name", "Bevy".to_string())];
+        assert_eq!(
+            interpolate_str(r"\{name} is {name}", &args, &Delimiters::default()),
+            "{name} is Bevy"
+        );
+        assert_eq!(
+            interpolate_str(r"\{name}", &[], &Delimiters::default()),
+            "{name}"
+        );
+        let delimiters = Delimiters::new("{{", "}}");
+        assert_eq!(
+            interpolate_str(r"\{{name}} is {{name}}, {name}", &args, &delimiters),
+            "{{name}} is Bevy, {name}"
+        );
+    }
+
+    #[test]
+    fn templates_without_replacements_are_borrowed() {
+        let args = [("name", "Bevy".to_string())];
+        for (template, args) in [("Hello", &args[..]), ("Hello {name}", &[][..])] {
+            let interpolated = interpolate(
+                Cow::Borrowed(template),
+                args,
+                &[],
+                "en",
+                &Delimiters::default(),
+            );
+            assert!(matches!(interpolated, Cow::Borrowed(borrowed) if borrowed == template));
+        }
+    }
+
+    #[test]
+    fn placeholders_format_their_number_with_the_spec() {
+        let args = [
+            ("damage", "12.25".to_string()),
+            ("name", "Bevy".to_string()),
+        ];
+        assert_eq!(
+            interpolate(
+                Cow::Borrowed("{damage:0.0} {damage:03} {name:0.0}"),
+                &args,
+                &[("damage", 12.35)],
+                "en",
+                &Delimiters::default(),
+            ),
+            "12.4 012 Bevy"
+        );
+    }
+
+    #[test]
+    fn format_spec_rounds_half_away_from_zero() {
+        assert_eq!(format_spec("en", 12.25, "0.0").as_deref(), Some("12.3"));
+        assert_eq!(format_spec("en", -12.25, ".1").as_deref(), Some("-12.3"));
+        assert_eq!(format_spec("en", 2.5, "0").as_deref(), Some("3"));
+        assert_eq!(format_spec("en", 0.004, "0.00").as_deref(), Some("0.00"));
+    }
+
+    #[test]
+    fn format_spec_pads_the_integer_and_fraction_digits() {
+        assert_eq!(format_spec("en", 7.0, "000").as_deref(), Some("007"));
+        assert_eq!(format_spec("en", 7.5, "03.1").as_deref(), Some("007.5"));
+        assert_eq!(format_spec("en", 7.0, "0.000").as_deref(), Some("7.000"));
+        assert_eq!(format_spec("en", 1234.0, "02").as_deref(), Some("1,234"));
+        assert_eq!(format_spec("fr", 7.5, "03.2").as_deref(), Some("007,50"));
+    }
+
+    #[test]
+    fn format_spec_rejects_invalid_specs() {
+        for spec in ["x", "3", "0.x", "0.33", "0a"] {
+            assert_eq!(format_spec("en", 1.0, spec), None, "{}", spec);
+        }
+        assert_eq!(format_spec("en", f64::NAN, "0.0"), None);
+    }
+}
@@ -0,0 +1,27 @@
+use bevy_egui::egui;
+
+use crate::translator::Translator;
+
+/// Helpers for the `bevy_egui` widgets, which can't use the text components, requires the `egui` feature
+///
+/// Fonts are not handled since egui manages its own
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_egui::{egui, EguiContexts};
+/// use bevy_simple_i18n::prelude::*;
+///
+/// fn debug_window(mut contexts: EguiContexts, tr: Translator) {
+///     egui::Window::new(tr.translate("debug.title")).show(contexts.ctx_mut(), |ui| {
+///         ui.label(tr.translate_with("debug.fps", &[("fps", 60)]));
+///         ui.label(tr.rich_text("debug.warning").strong());
+///     });
+/// }
+/// ```
+impl Translator<'_> {
+    /// Translates a key in the current locale into an egui [egui::RichText] that can be styled further
+    pub fn rich_text(&self, key: &str) -> egui::RichText {
+        egui::RichText::new(self.translate(key))
+    }
+}
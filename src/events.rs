@@ -0,0 +1,108 @@
+use bevy::{ecs::event::Event, reflect::Reflect};
+
+use crate::resources::TextDirection;
+
+/// Event sent by the plugin whenever the active locale of the [crate::resources::I18n] resource changes value
+///
+/// It's sent in the [crate::plugin::I18nSet] right before the translated text entities are updated
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_i18n::prelude::*;
+///
+/// fn on_locale_changed(mut events: EventReader<LocaleChanged>) {
+///     for event in events.read() {
+///         info!("Locale changed from {} to {}", event.previous, event.current);
+///     }
+/// }
+/// ```
+#[derive(Event, Reflect, Debug, Clone, PartialEq, Eq)]
+pub struct LocaleChanged {
+    /// The locale that was active before the change
+    pub previous: String,
+    /// The locale that is now active
+    pub current: String,
+}
+
+/// Event sent by the plugin when the base direction of the active locale changes, e.g. from `en` to `ar`,
+/// right after the [LocaleChanged] event, see [crate::resources::LayoutDirection]
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_i18n::prelude::*;
+///
+/// fn on_direction_changed(mut events: EventReader<DirectionChanged>) {
+///     for event in events.read() {
+///         info!("The layout is now {:?}", event.current);
+///     }
+/// }
+/// ```
+#[derive(Event, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectionChanged {
+    /// The direction of the previous locale
+    pub previous: TextDirection,
+    /// The direction of the locale that is now active
+    pub current: TextDirection,
+}
+
+/// Event sent once by the plugin when every dynamic font has finished loading
+///
+/// With [crate::plugin::I18nPlugin::with_lazy_fonts], it's sent again once the fonts of a newly selected locale are loaded
+///
+/// Useful for dismissing a loading screen, the text entities are updated with their fonts in the same frame
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_i18n::prelude::*;
+///
+/// fn on_fonts_loaded(mut events: EventReader<FontsLoaded>) {
+///     for _ in events.read() {
+///         info!("Fonts loaded");
+///     }
+/// }
+/// ```
+#[cfg(feature = "fonts")]
+#[derive(Event, Reflect, Debug, Clone, PartialEq, Eq)]
+pub struct FontsLoaded;
+
+/// Event sent by the plugin every frame while the dynamic fonts are loading
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_i18n::prelude::*;
+///
+/// fn draw_progress(mut events: EventReader<FontLoadingProgress>) {
+///     for progress in events.read() {
+///         info!("Loading fonts: {}/{}", progress.loaded, progress.total);
+///     }
+/// }
+/// ```
+#[cfg(feature = "fonts")]
+#[derive(Event, Reflect, Debug, Clone, PartialEq, Eq)]
+pub struct FontLoadingProgress {
+    /// Number of fonts that have finished loading
+    pub loaded: usize,
+    /// Total number of fonts that are loading
+    pub total: usize,
+}
+
+/// Event that re-translates every text entity, even though the [crate::resources::I18n] resource didn't change
+///
+/// Useful after changing the translation data by other means, e.g. injecting a user dictionary,
+/// the text entities are updated once in the [crate::plugin::I18nSet] even when the locale also changed
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_i18n::prelude::*;
+///
+/// fn refresh(mut events: EventWriter<RefreshTranslations>) {
+///     events.send(RefreshTranslations);
+/// }
+/// ```
+#[derive(Event, Reflect, Debug, Clone, PartialEq, Eq)]
+pub struct RefreshTranslations;
@@ -0,0 +1,55 @@
+//! Loader of the `otc` and `ttc` font collections, which Bevy's font loader doesn't register
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    text::Font,
+};
+
+/// Error returned by the [FontCollectionLoader]
+#[derive(Debug)]
+pub(crate) enum FontCollectionError {
+    Io(std::io::Error),
+    Parse(String),
+}
+
+impl std::fmt::Display for FontCollectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Could not read font collection: {}", err),
+            Self::Parse(err) => write!(f, "Could not parse font collection: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for FontCollectionError {}
+
+impl From<std::io::Error> for FontCollectionError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Asset loader for the font collections, Bevy renders the text with the last font of the collection
+#[derive(Default)]
+pub(crate) struct FontCollectionLoader;
+
+impl AssetLoader for FontCollectionLoader {
+    type Asset = Font;
+    type Settings = ();
+    type Error = FontCollectionError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Font::try_from_bytes(bytes).map_err(|err| FontCollectionError::Parse(err.to_string()))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["otc", "ttc"]
+    }
+}
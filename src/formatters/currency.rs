@@ -0,0 +1,118 @@
+use fixed_decimal::{FixedDecimal, Sign};
+
+/// Currencies without minor units in ISO 4217
+const ZERO_FRACTION_DIGITS: &[&str] = &[
+    "BIF", "CLP", "DJF", "GNF", "ISK", "JPY", "KMF", "KRW", "PYG", "RWF", "UGX", "UYI", "VND",
+    "VUV", "XAF", "XOF", "XPF",
+];
+/// Currencies with three minor units in ISO 4217
+const THREE_FRACTION_DIGITS: &[&str] = &["BHD", "IQD", "JOD", "KWD", "LYD", "OMR", "TND"];
+
+/// Symbols of the most common currencies, the ISO 4217 code is displayed for the other ones
+const SYMBOLS: &[(&str, &str)] = &[
+    ("AUD", "A$"),
+    ("BRL", "R$"),
+    ("CAD", "CA$"),
+    ("CNY", "CN¥"),
+    ("CZK", "Kč"),
+    ("DKK", "kr."),
+    ("EUR", "€"),
+    ("GBP", "£"),
+    ("HKD", "HK$"),
+    ("HUF", "Ft"),
+    ("ILS", "₪"),
+    ("INR", "₹"),
+    ("JPY", "¥"),
+    ("KRW", "₩"),
+    ("MXN", "MX$"),
+    ("NOK", "kr"),
+    ("NZD", "NZ$"),
+    ("PLN", "zł"),
+    ("RUB", "₽"),
+    ("SEK", "kr"),
+    ("THB", "฿"),
+    ("TRY", "₺"),
+    ("TWD", "NT$"),
+    ("UAH", "₴"),
+    ("USD", "$"),
+    ("VND", "₫"),
+];
+
+/// Languages that place the symbol after the amount, separated by a non-breaking space, `9,99 €`
+const SYMBOL_AFTER: &[&str] = &[
+    "bg", "cs", "da", "de", "el", "es", "et", "fi", "fr", "hr", "hu", "it", "lt", "lv", "nb", "nn",
+    "no", "pl", "pt", "ro", "ru", "sk", "sl", "sr", "sv", "uk", "vi",
+];
+/// Locales that place the symbol before the amount, separated by a non-breaking space, `€ 9,99`
+const SYMBOL_BEFORE_SPACED: &[&str] = &["nl", "pt-BR"];
+/// Regional variants of the [SYMBOL_AFTER] languages that place the symbol before the amount, `$9.99`
+const SYMBOL_BEFORE: &[&str] = &["es-419", "es-MX", "es-US"];
+
+/// Number of minor units of a currency, 2 unless listed otherwise in ISO 4217
+fn fraction_digits(currency: &str) -> i16 {
+    if ZERO_FRACTION_DIGITS.contains(&currency) {
+        0
+    } else if THREE_FRACTION_DIGITS.contains(&currency) {
+        3
+    } else {
+        2
+    }
+}
+
+/// Symbol of a currency in a locale, the Chinese and Japanese locales drop the prefix of their own currency
+fn symbol<'a>(language: &str, currency: &'a str) -> &'a str {
+    match (language, currency) {
+        ("zh", "CNY") => "¥",
+        ("ja", "JPY") => "￥",
+        _ => SYMBOLS
+            .iter()
+            .find(|(code, _)| *code == currency)
+            .map_or(currency, |(_, symbol)| symbol),
+    }
+}
+
+enum SymbolPosition {
+    Before,
+    BeforeSpaced,
+    After,
+}
+
+fn symbol_position(locale: &str, language: &str) -> SymbolPosition {
+    let matches = |list: &[&str]| list.iter().any(|entry| locale.eq_ignore_ascii_case(entry));
+    if matches(SYMBOL_BEFORE) {
+        SymbolPosition::Before
+    } else if matches(SYMBOL_BEFORE_SPACED) || SYMBOL_BEFORE_SPACED.contains(&language) {
+        SymbolPosition::BeforeSpaced
+    } else if SYMBOL_AFTER.contains(&language) {
+        SymbolPosition::After
+    } else {
+        SymbolPosition::Before
+    }
+}
+
+pub(crate) fn format_currency(locale: &str, amount: f64, currency: &str) -> String {
+    let currency = currency.to_ascii_uppercase();
+    let locale = locale.replace('_', "-");
+    let language = locale.split('-').next().unwrap_or_default();
+
+    let digits = fraction_digits(&currency);
+    let mut fixed_decimal: FixedDecimal = crate::components::utils::f64_to_fd(amount.abs());
+    fixed_decimal.half_even(-digits);
+    fixed_decimal.pad_end(-digits);
+    let negative = amount < 0.0 && !fixed_decimal.is_zero();
+    fixed_decimal.set_sign(Sign::None);
+
+    let number =
+        crate::components::utils::format_decimal(&Some(locale.clone()), &fixed_decimal, &currency);
+    let sign = if negative { "-" } else { "" };
+    let symbol = symbol(language, &currency);
+    match symbol_position(&locale, language) {
+        // Alphabetic symbols such as ISO 4217 codes are always separated, `CHF 9.99`
+        SymbolPosition::Before if symbol.ends_with(|c: char| c.is_ascii_alphabetic()) => {
+            format!("{}{}\u{a0}{}", sign, symbol, number)
+        }
+        SymbolPosition::Before => format!("{}{}{}", sign, symbol, number),
+        SymbolPosition::BeforeSpaced => format!("{}{}\u{a0}{}", sign, symbol, number),
+        SymbolPosition::After => format!("{}{}\u{a0}{}", sign, number, symbol),
+    }
+}
@@ -0,0 +1,92 @@
+use bevy::reflect::Reflect;
+use icu_calendar::{DateTime, Gregorian};
+use icu_datetime::{options::length, TimeFormatter, TypedDateFormatter, TypedDateTimeFormatter};
+
+/// Length of the localized date and time strings
+///
+/// The month and weekday names, as well as the order of the fields, come from the locale data
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum DateTimeStyle {
+    /// `1/2/24`, `2:05 PM` in `en`
+    Short,
+    /// `Jan 2, 2024`, `2:05:09 PM` in `en`
+    #[default]
+    Medium,
+    /// `January 2, 2024` in `en`, times use the [DateTimeStyle::Medium] length since they're formatted without a time zone
+    Long,
+}
+
+impl DateTimeStyle {
+    fn date(self) -> length::Date {
+        match self {
+            Self::Short => length::Date::Short,
+            Self::Medium => length::Date::Medium,
+            Self::Long => length::Date::Long,
+        }
+    }
+
+    fn time(self) -> length::Time {
+        match self {
+            Self::Short => length::Time::Short,
+            Self::Medium | Self::Long => length::Time::Medium,
+        }
+    }
+}
+
+pub(crate) fn format_date(locale: &str, timestamp: i64, style: DateTimeStyle) -> String {
+    let datetime = to_datetime(timestamp);
+    TypedDateFormatter::<Gregorian>::try_new_with_length(
+        &resolve_locale(locale).into(),
+        style.date(),
+    )
+    .unwrap_or_else(|_| panic!("Failed to create DateFormatter for locale: {}", locale))
+    .format_to_string(&datetime.date)
+}
+
+pub(crate) fn format_time(locale: &str, timestamp: i64, style: DateTimeStyle) -> String {
+    let datetime = to_datetime(timestamp);
+    TimeFormatter::try_new_with_length(&resolve_locale(locale).into(), style.time())
+        .unwrap_or_else(|_| panic!("Failed to create TimeFormatter for locale: {}", locale))
+        .format_to_string(&datetime)
+}
+
+pub(crate) fn format_datetime(locale: &str, timestamp: i64, style: DateTimeStyle) -> String {
+    let datetime = to_datetime(timestamp);
+    let options = length::Bag::from_date_time_style(style.date(), style.time());
+    TypedDateTimeFormatter::<Gregorian>::try_new(&resolve_locale(locale).into(), options.into())
+        .unwrap_or_else(|_| panic!("Failed to create DateTimeFormatter for locale: {}", locale))
+        .format_to_string(&datetime)
+}
+
+fn resolve_locale(locale: &str) -> icu_locid::Locale {
+    locale
+        .parse()
+        .unwrap_or_else(|_| panic!("Invalid locale: {}", locale))
+}
+
+/// Converts a Unix timestamp in seconds to a UTC Gregorian date time
+fn to_datetime(timestamp: i64) -> DateTime<Gregorian> {
+    let days = timestamp.div_euclid(86_400);
+    let seconds = timestamp.rem_euclid(86_400);
+
+    // Civil from days, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+
+    DateTime::try_new_gregorian_datetime(
+        year,
+        month,
+        day,
+        (seconds / 3_600) as u8,
+        (seconds % 3_600 / 60) as u8,
+        (seconds % 60) as u8,
+    )
+    .unwrap_or_else(|_| panic!("Failed to create DateTime from timestamp: {}", timestamp))
+}
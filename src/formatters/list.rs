@@ -0,0 +1,30 @@
+use bevy::reflect::Reflect;
+use icu_list::{ListFormatter, ListLength};
+
+/// Conjunction used to join the items of a list
+///
+/// The separators, the conjunction word and its placement come from the locale data
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum ListStyle {
+    /// `apples, oranges, and pears` in `en`, `pommes, oranges et poires` in `fr`
+    #[default]
+    And,
+    /// `apples, oranges, or pears` in `en`, `pommes, oranges ou poires` in `fr`
+    Or,
+    /// A list of units without a conjunction, `3 feet, 7 inches` in `en`
+    Unit,
+}
+
+pub(crate) fn format_list(locale: &str, items: &[&str], style: ListStyle) -> String {
+    let locale: icu_locid::Locale = locale
+        .parse()
+        .unwrap_or_else(|_| panic!("Invalid locale: {}", locale));
+    let locale = (&locale).into();
+    match style {
+        ListStyle::And => ListFormatter::try_new_and_with_length(&locale, ListLength::Wide),
+        ListStyle::Or => ListFormatter::try_new_or_with_length(&locale, ListLength::Wide),
+        ListStyle::Unit => ListFormatter::try_new_unit_with_length(&locale, ListLength::Wide),
+    }
+    .unwrap_or_else(|_| panic!("Failed to create ListFormatter for locale: {}", locale))
+    .format_to_string(items.iter())
+}
@@ -0,0 +1,25 @@
+#[cfg(feature = "numbers")]
+mod currency;
+#[cfg(feature = "datetime")]
+mod datetime;
+#[cfg(feature = "list_format")]
+mod list;
+#[cfg(feature = "plurals")]
+mod ordinal;
+#[cfg(feature = "plurals")]
+mod relative_time;
+#[cfg(feature = "numbers")]
+mod units;
+
+#[cfg(feature = "numbers")]
+pub(crate) use currency::*;
+#[cfg(feature = "datetime")]
+pub use datetime::*;
+#[cfg(feature = "list_format")]
+pub use list::*;
+#[cfg(feature = "plurals")]
+pub(crate) use ordinal::*;
+#[cfg(feature = "plurals")]
+pub(crate) use relative_time::*;
+#[cfg(feature = "numbers")]
+pub use units::*;
@@ -0,0 +1,85 @@
+use icu_locid::Locale;
+use icu_plurals::{PluralCategory, PluralRules};
+
+/// Ordinal patterns of the languages for each ordinal plural category, the number replaces `{0}`
+///
+/// Languages with grammatical gender use the masculine form, `1er` in `fr`
+const PATTERNS: &[(&str, &[(&str, &str)])] = &[
+    ("cs", &[("other", "{0}.")]),
+    ("da", &[("other", "{0}.")]),
+    ("de", &[("other", "{0}.")]),
+    (
+        "en",
+        &[
+            ("one", "{0}st"),
+            ("two", "{0}nd"),
+            ("few", "{0}rd"),
+            ("other", "{0}th"),
+        ],
+    ),
+    ("es", &[("other", "{0}.º")]),
+    ("fi", &[("other", "{0}.")]),
+    ("fr", &[("one", "{0}er"), ("other", "{0}e")]),
+    ("hu", &[("other", "{0}.")]),
+    ("it", &[("other", "{0}º")]),
+    ("ja", &[("other", "第{0}")]),
+    ("ko", &[("other", "{0}번째")]),
+    ("nb", &[("other", "{0}.")]),
+    ("nl", &[("other", "{0}e")]),
+    ("no", &[("other", "{0}.")]),
+    ("pl", &[("other", "{0}.")]),
+    ("pt", &[("other", "{0}º")]),
+    ("ru", &[("other", "{0}-й")]),
+    ("sl", &[("other", "{0}.")]),
+    ("sv", &[("one", "{0}:a"), ("other", "{0}:e")]),
+    ("th", &[("other", "ที่ {0}")]),
+    ("tr", &[("other", "{0}.")]),
+    ("uk", &[("other", "{0}-й")]),
+    ("zh", &[("other", "第{0}")]),
+];
+
+/// Formats a number as an ordinal with the pattern of the CLDR ordinal plural category of the locale,
+/// `1st`, `2nd`, `3rd`, `11th`, `21st` in `en`, `1er`, `2e` in `fr`
+///
+/// Languages without patterns get the number as-is
+pub(crate) fn format_ordinal(locale: &str, number: i64) -> String {
+    #[cfg(feature = "numbers")]
+    let formatted = crate::components::utils::format_decimal(
+        &Some(locale.to_string()),
+        &fixed_decimal::FixedDecimal::from(number),
+        number,
+    );
+    #[cfg(not(feature = "numbers"))]
+    let formatted = number.to_string();
+
+    let Ok(locale) = locale.replace('_', "-").parse::<Locale>() else {
+        return formatted;
+    };
+    let Some((_, patterns)) = PATTERNS
+        .iter()
+        .find(|(language, _)| *language == locale.id.language.as_str())
+    else {
+        return formatted;
+    };
+    let category = match PluralRules::try_new_ordinal(&locale.clone().into()) {
+        Ok(rules) => rules.category_for(number),
+        Err(err) => {
+            bevy::log::warn!("Failed to load ordinal rules for {}: {}", locale, err);
+            PluralCategory::Other
+        }
+    };
+    let category = match category {
+        PluralCategory::Zero => "zero",
+        PluralCategory::One => "one",
+        PluralCategory::Two => "two",
+        PluralCategory::Few => "few",
+        PluralCategory::Many => "many",
+        PluralCategory::Other => "other",
+    };
+    let (_, pattern) = patterns
+        .iter()
+        .find(|(c, _)| *c == category)
+        .or_else(|| patterns.iter().find(|(c, _)| *c == "other"))
+        .expect("Every language has an `other` pattern");
+    pattern.replace("{0}", &formatted)
+}
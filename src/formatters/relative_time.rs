@@ -0,0 +1,319 @@
+/// Patterns of a unit for each plural category, `(category, past, future)`, the number replaces `{0}`
+type UnitPatterns = &'static [(&'static str, &'static str, &'static str)];
+
+/// Relative time patterns of a language, from the long forms of the CLDR data
+struct Patterns {
+    language: &'static str,
+    now: &'static str,
+    /// Seconds, minutes, hours and days
+    units: [UnitPatterns; 4],
+}
+
+/// Number of seconds from which each unit is used, along with its length in seconds
+const UNITS: [(i64, i64); 4] = [(0, 1), (60, 60), (3_600, 3_600), (86_400, 86_400)];
+
+const PATTERNS: &[Patterns] = &[
+    Patterns {
+        language: "de",
+        now: "jetzt",
+        units: [
+            &[
+                ("one", "vor {0} Sekunde", "in {0} Sekunde"),
+                ("other", "vor {0} Sekunden", "in {0} Sekunden"),
+            ],
+            &[
+                ("one", "vor {0} Minute", "in {0} Minute"),
+                ("other", "vor {0} Minuten", "in {0} Minuten"),
+            ],
+            &[
+                ("one", "vor {0} Stunde", "in {0} Stunde"),
+                ("other", "vor {0} Stunden", "in {0} Stunden"),
+            ],
+            &[
+                ("one", "vor {0} Tag", "in {0} Tag"),
+                ("other", "vor {0} Tagen", "in {0} Tagen"),
+            ],
+        ],
+    },
+    Patterns {
+        language: "en",
+        now: "now",
+        units: [
+            &[
+                ("one", "{0} second ago", "in {0} second"),
+                ("other", "{0} seconds ago", "in {0} seconds"),
+            ],
+            &[
+                ("one", "{0} minute ago", "in {0} minute"),
+                ("other", "{0} minutes ago", "in {0} minutes"),
+            ],
+            &[
+                ("one", "{0} hour ago", "in {0} hour"),
+                ("other", "{0} hours ago", "in {0} hours"),
+            ],
+            &[
+                ("one", "{0} day ago", "in {0} day"),
+                ("other", "{0} days ago", "in {0} days"),
+            ],
+        ],
+    },
+    Patterns {
+        language: "es",
+        now: "ahora",
+        units: [
+            &[
+                ("one", "hace {0} segundo", "dentro de {0} segundo"),
+                ("other", "hace {0} segundos", "dentro de {0} segundos"),
+            ],
+            &[
+                ("one", "hace {0} minuto", "dentro de {0} minuto"),
+                ("other", "hace {0} minutos", "dentro de {0} minutos"),
+            ],
+            &[
+                ("one", "hace {0} hora", "dentro de {0} hora"),
+                ("other", "hace {0} horas", "dentro de {0} horas"),
+            ],
+            &[
+                ("one", "hace {0} día", "dentro de {0} día"),
+                ("other", "hace {0} días", "dentro de {0} días"),
+            ],
+        ],
+    },
+    Patterns {
+        language: "fr",
+        now: "maintenant",
+        units: [
+            &[
+                ("one", "il y a {0} seconde", "dans {0} seconde"),
+                ("other", "il y a {0} secondes", "dans {0} secondes"),
+            ],
+            &[
+                ("one", "il y a {0} minute", "dans {0} minute"),
+                ("other", "il y a {0} minutes", "dans {0} minutes"),
+            ],
+            &[
+                ("one", "il y a {0} heure", "dans {0} heure"),
+                ("other", "il y a {0} heures", "dans {0} heures"),
+            ],
+            &[
+                ("one", "il y a {0} jour", "dans {0} jour"),
+                ("other", "il y a {0} jours", "dans {0} jours"),
+            ],
+        ],
+    },
+    Patterns {
+        language: "it",
+        now: "ora",
+        units: [
+            &[
+                ("one", "{0} secondo fa", "tra {0} secondo"),
+                ("other", "{0} secondi fa", "tra {0} secondi"),
+            ],
+            &[
+                ("one", "{0} minuto fa", "tra {0} minuto"),
+                ("other", "{0} minuti fa", "tra {0} minuti"),
+            ],
+            &[
+                ("one", "{0} ora fa", "tra {0} ora"),
+                ("other", "{0} ore fa", "tra {0} ore"),
+            ],
+            &[
+                ("one", "{0} giorno fa", "tra {0} giorno"),
+                ("other", "{0} giorni fa", "tra {0} giorni"),
+            ],
+        ],
+    },
+    Patterns {
+        language: "ja",
+        now: "今",
+        units: [
+            &[("other", "{0} 秒前", "{0} 秒後")],
+            &[("other", "{0} 分前", "{0} 分後")],
+            &[("other", "{0} 時間前", "{0} 時間後")],
+            &[("other", "{0} 日前", "{0} 日後")],
+        ],
+    },
+    Patterns {
+        language: "ko",
+        now: "지금",
+        units: [
+            &[("other", "{0}초 전", "{0}초 후")],
+            &[("other", "{0}분 전", "{0}분 후")],
+            &[("other", "{0}시간 전", "{0}시간 후")],
+            &[("other", "{0}일 전", "{0}일 후")],
+        ],
+    },
+    Patterns {
+        language: "pl",
+        now: "teraz",
+        units: [
+            &[
+                ("one", "{0} sekundę temu", "za {0} sekundę"),
+                ("few", "{0} sekundy temu", "za {0} sekundy"),
+                ("many", "{0} sekund temu", "za {0} sekund"),
+                ("other", "{0} sekundy temu", "za {0} sekundy"),
+            ],
+            &[
+                ("one", "{0} minutę temu", "za {0} minutę"),
+                ("few", "{0} minuty temu", "za {0} minuty"),
+                ("many", "{0} minut temu", "za {0} minut"),
+                ("other", "{0} minuty temu", "za {0} minuty"),
+            ],
+            &[
+                ("one", "{0} godzinę temu", "za {0} godzinę"),
+                ("few", "{0} godziny temu", "za {0} godziny"),
+                ("many", "{0} godzin temu", "za {0} godzin"),
+                ("other", "{0} godziny temu", "za {0} godziny"),
+            ],
+            &[
+                ("one", "{0} dzień temu", "za {0} dzień"),
+                ("few", "{0} dni temu", "za {0} dni"),
+                ("many", "{0} dni temu", "za {0} dni"),
+                ("other", "{0} dnia temu", "za {0} dnia"),
+            ],
+        ],
+    },
+    Patterns {
+        language: "pt",
+        now: "agora",
+        units: [
+            &[
+                ("one", "há {0} segundo", "em {0} segundo"),
+                ("other", "há {0} segundos", "em {0} segundos"),
+            ],
+            &[
+                ("one", "há {0} minuto", "em {0} minuto"),
+                ("other", "há {0} minutos", "em {0} minutos"),
+            ],
+            &[
+                ("one", "há {0} hora", "em {0} hora"),
+                ("other", "há {0} horas", "em {0} horas"),
+            ],
+            &[
+                ("one", "há {0} dia", "em {0} dia"),
+                ("other", "há {0} dias", "em {0} dias"),
+            ],
+        ],
+    },
+    Patterns {
+        language: "ru",
+        now: "сейчас",
+        units: [
+            &[
+                ("one", "{0} секунду назад", "через {0} секунду"),
+                ("few", "{0} секунды назад", "через {0} секунды"),
+                ("many", "{0} секунд назад", "через {0} секунд"),
+                ("other", "{0} секунды назад", "через {0} секунды"),
+            ],
+            &[
+                ("one", "{0} минуту назад", "через {0} минуту"),
+                ("few", "{0} минуты назад", "через {0} минуты"),
+                ("many", "{0} минут назад", "через {0} минут"),
+                ("other", "{0} минуты назад", "через {0} минуты"),
+            ],
+            &[
+                ("one", "{0} час назад", "через {0} час"),
+                ("few", "{0} часа назад", "через {0} часа"),
+                ("many", "{0} часов назад", "через {0} часов"),
+                ("other", "{0} часа назад", "через {0} часа"),
+            ],
+            &[
+                ("one", "{0} день назад", "через {0} день"),
+                ("few", "{0} дня назад", "через {0} дня"),
+                ("many", "{0} дней назад", "через {0} дней"),
+                ("other", "{0} дня назад", "через {0} дня"),
+            ],
+        ],
+    },
+    Patterns {
+        language: "zh",
+        now: "现在",
+        units: [
+            &[("other", "{0}秒钟前", "{0}秒钟后")],
+            &[("other", "{0}分钟前", "{0}分钟后")],
+            &[("other", "{0}小时前", "{0}小时后")],
+            &[("other", "{0}天前", "{0}天后")],
+        ],
+    },
+    Patterns {
+        language: "zh-Hant",
+        now: "現在",
+        units: [
+            &[("other", "{0} 秒前", "{0} 秒後")],
+            &[("other", "{0} 分鐘前", "{0} 分鐘後")],
+            &[("other", "{0} 小時前", "{0} 小時後")],
+            &[("other", "{0} 天前", "{0} 天後")],
+        ],
+    },
+];
+
+/// Formats an offset in seconds from now as a relative time, in the largest unit that fits it,
+/// `-300` -> `5 minutes ago`, `7200` -> `in 2 hours` in `en`
+///
+/// Languages without patterns use the `en` ones, along with its plural rules
+pub(crate) fn format_relative_time(locale: &str, seconds: i64) -> String {
+    let (patterns, plural_locale) = match find_patterns(locale) {
+        Some(patterns) => (patterns, locale),
+        None => (
+            PATTERNS
+                .iter()
+                .find(|patterns| patterns.language == "en")
+                .expect("The `en` patterns exist"),
+            "en",
+        ),
+    };
+    let magnitude = seconds.saturating_abs();
+    let unit = UNITS
+        .iter()
+        .rposition(|(from, _)| magnitude >= *from)
+        .unwrap_or_default();
+    let value = magnitude / UNITS[unit].1;
+    if value == 0 {
+        return patterns.now.to_string();
+    }
+
+    let count = fixed_decimal::FixedDecimal::from(value);
+    let category =
+        crate::components::utils::plural_category(&Some(plural_locale.to_string()), &count, value);
+    #[cfg(feature = "numbers")]
+    let number = crate::components::utils::format_decimal(&Some(locale.to_string()), &count, value);
+    #[cfg(not(feature = "numbers"))]
+    let number = value.to_string();
+
+    let units = patterns.units[unit];
+    let (_, past, future) = units
+        .iter()
+        .find(|(c, _, _)| *c == category)
+        .or_else(|| units.iter().find(|(c, _, _)| *c == "other"))
+        .expect("Every unit has an `other` pattern");
+    let pattern = if seconds < 0 { past } else { future };
+    pattern.replace("{0}", &number)
+}
+
+/// Patterns of the language of a locale, the traditional Chinese ones for `zh-TW`, `zh-HK` and `zh-Hant`
+fn find_patterns(locale: &str) -> Option<&'static Patterns> {
+    let language = match locale.replace('_', "-").parse::<icu_locid::Locale>() {
+        Ok(locale) if locale.id.language.as_str() == "zh" => {
+            let traditional = locale
+                .id
+                .script
+                .map(|script| script.as_str() == "Hant")
+                .unwrap_or_else(|| {
+                    locale
+                        .id
+                        .region
+                        .is_some_and(|region| matches!(region.as_str(), "TW" | "HK" | "MO"))
+                });
+            if traditional {
+                "zh-Hant".to_string()
+            } else {
+                "zh".to_string()
+            }
+        }
+        Ok(locale) => locale.id.language.to_string(),
+        Err(_) => String::new(),
+    };
+    PATTERNS
+        .iter()
+        .find(|patterns| patterns.language == language)
+}
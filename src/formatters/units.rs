@@ -0,0 +1,157 @@
+use bevy::reflect::Reflect;
+use icu_locid::Locale;
+
+/// Kind of measurement formatted by [crate::resources::I18n::format_unit], the values are in SI units
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum Unit {
+    /// A length in meters, `m`/`km` or `ft`/`mi`
+    Length,
+    /// A mass in kilograms, `g`/`kg` or `oz`/`lb`
+    Mass,
+    /// A speed in meters per second, `km/h` or `mph`
+    Speed,
+}
+
+/// System of units that measurements are displayed in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum MeasurementSystem {
+    /// Meters, kilograms and kilometers per hour
+    #[default]
+    Metric,
+    /// Feet, miles, pounds and miles per hour, as used in the United States
+    Imperial,
+}
+
+impl MeasurementSystem {
+    /// Regions that use the imperial system, following the `measurementSystem` of the CLDR data
+    const IMPERIAL_REGIONS: [&'static str; 3] = ["LR", "MM", "US"];
+    /// Languages whose most likely region uses the imperial system when no region is specified
+    const IMPERIAL_LANGUAGES: [&'static str; 2] = ["en", "my"];
+
+    /// Resolves the measurement system of a locale from its region, or its language when no region is specified
+    ///
+    /// `en-US` -> [MeasurementSystem::Imperial], `en-GB` -> [MeasurementSystem::Metric], `en` -> [MeasurementSystem::Imperial]
+    pub fn of(locale: &str) -> Self {
+        let Ok(locale) = locale.replace('_', "-").parse::<Locale>() else {
+            return Self::Metric;
+        };
+        let imperial = match locale.id.region {
+            Some(region) => Self::IMPERIAL_REGIONS.contains(&region.as_str()),
+            None => Self::IMPERIAL_LANGUAGES.contains(&locale.id.language.as_str()),
+        };
+        if imperial {
+            Self::Imperial
+        } else {
+            Self::Metric
+        }
+    }
+}
+
+/// Units of measurement a value can be displayed in
+#[derive(Clone, Copy)]
+enum Symbol {
+    Meter,
+    Kilometer,
+    Foot,
+    Mile,
+    Gram,
+    Kilogram,
+    Ounce,
+    Pound,
+    KilometerPerHour,
+    MilePerHour,
+}
+
+/// Patterns of the short unit names of the languages that don't use the international symbols, the number replaces `{0}`
+const PATTERNS: &[(&str, [&str; 10])] = &[
+    (
+        "ru",
+        [
+            "{0} м",
+            "{0} км",
+            "{0} фт",
+            "{0} ми",
+            "{0} г",
+            "{0} кг",
+            "{0} унц.",
+            "{0} фнт",
+            "{0} км/ч",
+            "{0} ми/ч",
+        ],
+    ),
+    (
+        "zh",
+        [
+            "{0}米",
+            "{0}公里",
+            "{0}英尺",
+            "{0}英里",
+            "{0}克",
+            "{0}公斤",
+            "{0}盎司",
+            "{0}磅",
+            "{0}公里/小时",
+            "{0}英里/小时",
+        ],
+    ),
+];
+
+/// Patterns of the international symbols
+const DEFAULT_PATTERNS: [&str; 10] = [
+    "{0} m", "{0} km", "{0} ft", "{0} mi", "{0} g", "{0} kg", "{0} oz", "{0} lb", "{0} km/h",
+    "{0} mph",
+];
+
+const METERS_PER_MILE: f64 = 1_609.344;
+const METERS_PER_FOOT: f64 = 0.3048;
+const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+const KILOGRAMS_PER_OUNCE: f64 = 0.028_349_523_125;
+
+/// Formats a measurement in the larger unit of the system once the value reaches one of it,
+/// `350 m`, `1.2 km`, `1,200 ft`, `2.5 mi`, with at most one fraction digit
+pub(crate) fn format_unit(
+    locale: &str,
+    value: f64,
+    unit: Unit,
+    system: MeasurementSystem,
+) -> String {
+    let (value, symbol) = match (unit, system) {
+        (Unit::Length, MeasurementSystem::Metric) if value.abs() >= 1_000.0 => {
+            (value / 1_000.0, Symbol::Kilometer)
+        }
+        (Unit::Length, MeasurementSystem::Metric) => (value, Symbol::Meter),
+        (Unit::Length, MeasurementSystem::Imperial) if value.abs() >= METERS_PER_MILE => {
+            (value / METERS_PER_MILE, Symbol::Mile)
+        }
+        (Unit::Length, MeasurementSystem::Imperial) => (value / METERS_PER_FOOT, Symbol::Foot),
+        (Unit::Mass, MeasurementSystem::Metric) if value.abs() >= 1.0 => (value, Symbol::Kilogram),
+        (Unit::Mass, MeasurementSystem::Metric) => (value * 1_000.0, Symbol::Gram),
+        (Unit::Mass, MeasurementSystem::Imperial) if value.abs() >= KILOGRAMS_PER_POUND => {
+            (value / KILOGRAMS_PER_POUND, Symbol::Pound)
+        }
+        (Unit::Mass, MeasurementSystem::Imperial) => (value / KILOGRAMS_PER_OUNCE, Symbol::Ounce),
+        (Unit::Speed, MeasurementSystem::Metric) => (value * 3.6, Symbol::KilometerPerHour),
+        (Unit::Speed, MeasurementSystem::Imperial) => {
+            (value * 3_600.0 / METERS_PER_MILE, Symbol::MilePerHour)
+        }
+    };
+
+    let rounded = (value * 10.0).round() / 10.0;
+    let fixed_decimal = crate::components::utils::f64_to_fd(rounded);
+    let number = crate::components::utils::format_decimal(
+        &Some(locale.to_string()),
+        &fixed_decimal,
+        rounded,
+    );
+
+    let language = locale
+        .replace('_', "-")
+        .parse::<Locale>()
+        .map(|locale| locale.id.language.to_string())
+        .unwrap_or_default();
+    let patterns = PATTERNS
+        .iter()
+        .find(|(l, _)| *l == language)
+        .map_or(&DEFAULT_PATTERNS, |(_, patterns)| patterns);
+    patterns[symbol as usize].replace("{0}", &number)
+}
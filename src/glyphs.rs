@@ -0,0 +1,11 @@
+//! Characters of the embedded translations of every locale, collected by the build script to warm up the glyph atlas
+
+include!(concat!(env!("OUT_DIR"), "/bevy_simple_i18n_glyphs.rs"));
+
+/// Unique characters of the embedded translations of a locale, without whitespace
+pub(crate) fn embedded(locale: &str) -> Option<&'static str> {
+    LOCALE_GLYPHS
+        .binary_search_by_key(&locale, |(l, _)| l)
+        .ok()
+        .map(|index| LOCALE_GLYPHS[index].1)
+}
@@ -0,0 +1,16 @@
+//! Keys of the translations as constants, generated by the build script from the locale files of every locale
+//!
+//! The segments of a key are nested modules, `menu.start` -> `keys::menu::START`, so a renamed or removed key
+//! fails the build wherever its constant is used. The segments are sanitized into identifiers,
+//! `main-menu` -> `main_menu`, `2d` -> `_2d`, and the parent keys of plural variants are also constants,
+//! `messages.items` for `messages.items.one`
+//!
+//! # Example
+//! ```
+//! use bevy_simple_i18n::{keys, prelude::*};
+//!
+//! let text = I18nText::new(keys::messages::HELLO).with_arg("name", "Bevy User");
+//! let hello = I18n::default().t(keys::HELLO);
+//! ```
+
+include!(concat!(env!("OUT_DIR"), "/bevy_simple_i18n_keys_module.rs"));
@@ -1,11 +1,105 @@
+mod arg;
+#[cfg(feature = "runtime_translations")]
+mod assets;
+#[cfg(feature = "audio")]
+mod audio;
+#[cfg(any(feature = "fluent", feature = "gettext", feature = "csv"))]
+mod backends;
 mod components;
+#[cfg(feature = "egui")]
+mod egui;
+mod events;
+#[cfg(feature = "fonts")]
+mod font_collection;
+#[cfg(any(
+    feature = "numbers",
+    feature = "plurals",
+    feature = "datetime",
+    feature = "list_format"
+))]
+mod formatters;
+#[cfg(feature = "fonts")]
+mod glyphs;
+pub mod keys;
+mod lists;
+mod localized_assets;
+mod markup;
+mod metadata;
+mod placeholders;
 mod plugin;
+#[cfg(feature = "pseudolocale")]
+mod pseudo;
+mod references;
+mod resolved;
 mod resources;
+mod roots;
+mod ruby;
+mod select;
+mod translator;
 
+#[cfg(not(feature = "external_translations"))]
 rust_i18n::i18n!("assets/locales");
+// Nothing is embedded, the folder doesn't exist and the locale files are loaded from the assets folder at runtime
+#[cfg(feature = "external_translations")]
+rust_i18n::i18n!("assets/locales/.external");
+
+include!(concat!(env!("OUT_DIR"), "/bevy_simple_i18n_keys.rs"));
+
+/// Translates a key in the current locale with [prelude::I18n::t], failing to compile when the key doesn't exist in any locale
+///
+/// Named arguments replace the matching `{name}` placeholders, like [prelude::I18n::t_with].
+/// It also fails to compile when the arguments don't exactly cover the placeholders of the key in every locale
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_i18n::{prelude::*, t};
+///
+/// fn log_greeting(i18n: Res<I18n>) {
+///     info!("{}", t!(i18n, "hello"));
+///     info!("{}", t!(i18n, "messages.hello", name = "Bevy User"));
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use bevy_simple_i18n::{prelude::*, t};
+/// # let i18n = I18n::default();
+/// let text = t!(i18n, "helo");
+/// ```
+///
+/// ```compile_fail
+/// # use bevy_simple_i18n::{prelude::*, t};
+/// # let i18n = I18n::default();
+/// // `Hello %{name}` expects a `name`
+/// let text = t!(i18n, "messages.hello", naem = "Bevy User");
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($i18n:expr, $key:tt $(,)?) => {{
+        $crate::__i18n_key!($key);
+        const _: () = $crate::__check_args($key, &[]);
+        $i18n.t($key)
+    }};
+    ($i18n:expr, $key:tt, $($name:ident = $value:expr),+ $(,)?) => {{
+        $crate::__i18n_key!($key);
+        const _: () = $crate::__check_args($key, &[$(stringify!($name)),+]);
+        $i18n.t_with($key, &[$((stringify!($name), ::std::string::ToString::to_string(&$value))),+])
+    }};
+}
+
+#[doc(hidden)]
+pub use placeholders::check_args as __check_args;
 
 pub mod prelude {
+    pub use crate::arg::{Arg, ToArg};
     pub use crate::components::*;
-    pub use crate::plugin::I18nPlugin;
+    pub use crate::events::*;
+    #[cfg(any(feature = "numbers", feature = "datetime", feature = "list_format"))]
+    pub use crate::formatters::*;
+    pub use crate::plugin::{I18nPlugin, I18nSet};
+    #[cfg(feature = "pseudolocale")]
+    pub use crate::pseudo::PSEUDO_LOCALE;
     pub use crate::resources::*;
+    pub use crate::ruby::RubySegment;
+    pub use crate::translator::Translator;
 }
@@ -0,0 +1,13 @@
+//! Elements of the lists of the locale files, which `rust-i18n` doesn't embed, collected by the build script
+
+include!(concat!(env!("OUT_DIR"), "/bevy_simple_i18n_lists.rs"));
+
+/// Translation of a list element of a locale, `tips.0`
+pub(crate) fn translate(locale: &str, key: &str) -> Option<&'static str> {
+    let index = LIST_TRANSLATIONS
+        .binary_search_by_key(&locale, |(l, _)| l)
+        .ok()?;
+    let table = LIST_TRANSLATIONS[index].1;
+    let index = table.binary_search_by_key(&key, |(k, _)| k).ok()?;
+    Some(table[index].1)
+}
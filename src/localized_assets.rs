@@ -0,0 +1,19 @@
+//! Assets with a locale suffix found in the assets folder by the build script, `images/signpost.fr.png`
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/bevy_simple_i18n_localized_assets.rs"
+));
+
+/// Variant of an asset for a locale, `images/signpost.png` -> `images/signpost.fr.png`, if it exists
+pub(crate) fn find(path: &str, locale: &str) -> Option<&'static str> {
+    let (stem, ext) = match path.rfind('.').filter(|dot| !path[*dot..].contains('/')) {
+        Some(dot) => (&path[..dot], &path[dot..]),
+        None => (path, ""),
+    };
+    let variant = format!("{}.{}{}", stem, locale, ext);
+    LOCALIZED_ASSETS
+        .binary_search(&variant.as_str())
+        .ok()
+        .map(|index| LOCALIZED_ASSETS[index])
+}
@@ -0,0 +1,126 @@
+use bevy::color::{Color, Srgba};
+
+#[cfg(feature = "fonts")]
+use crate::resources::FontStyle;
+
+/// Styling of a run of translated text, set by the markup tags surrounding it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct MarkupStyle {
+    pub(crate) bold: bool,
+    pub(crate) italic: bool,
+    pub(crate) color: Option<Color>,
+}
+
+impl MarkupStyle {
+    #[cfg(feature = "fonts")]
+    /// Style of the font to render the run with, emphasizing the base style of the entity,
+    /// bold runs are at least `700`
+    pub(crate) fn font_style(&self, base: FontStyle) -> FontStyle {
+        let weight = if self.bold {
+            base.weight().max(700)
+        } else {
+            base.weight()
+        };
+        FontStyle::weighted(weight, self.italic || base.is_italic())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TagKind {
+    Bold,
+    Italic,
+    Color,
+}
+
+enum Tag {
+    Open(TagKind, Option<Color>),
+    Close(TagKind),
+}
+
+/// Splits a translation into runs of text styled by its markup tags,
+/// `Press <b>E</b> to <color=#ff0>open</color>` -> `Press `, `E` (bold), ` to `, `open` (yellow)
+///
+/// Supports `<b>`, `<i>` and `<color=#rgb>` (or `#rrggbb`, `#rrggbbaa`), tags can be nested.
+/// Unknown or mismatched tags are kept as text and unclosed tags style the rest of the text
+///
+/// `None` when the text has no markup
+pub(crate) fn parse_markup(text: &str) -> Option<Vec<(String, MarkupStyle)>> {
+    if !text.contains('<') {
+        return None;
+    }
+    let mut runs: Vec<(String, MarkupStyle)> = vec![];
+    let mut stack: Vec<(TagKind, MarkupStyle)> = vec![];
+    let mut style = MarkupStyle::default();
+    let mut has_markup = false;
+    let mut rest = text;
+
+    while let Some(start) = rest.find('<') {
+        push_run(&mut runs, &rest[..start], &style);
+        rest = &rest[start..];
+        let tag = rest
+            .find('>')
+            .and_then(|end| parse_tag(&rest[1..end]).map(|tag| (tag, end)));
+        match tag {
+            Some((Tag::Open(kind, color), end)) => {
+                stack.push((kind, style.clone()));
+                match kind {
+                    TagKind::Bold => style.bold = true,
+                    TagKind::Italic => style.italic = true,
+                    TagKind::Color => style.color = color,
+                }
+                has_markup = true;
+                rest = &rest[end + 1..];
+            }
+            Some((Tag::Close(kind), end))
+                if stack.last().is_some_and(|(open, _)| *open == kind) =>
+            {
+                style = stack.pop().unwrap().1;
+                has_markup = true;
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                push_run(&mut runs, "<", &style);
+                rest = &rest[1..];
+            }
+        }
+    }
+    push_run(&mut runs, rest, &style);
+
+    has_markup.then_some(runs)
+}
+
+fn parse_tag(tag: &str) -> Option<Tag> {
+    match tag.trim() {
+        "b" => Some(Tag::Open(TagKind::Bold, None)),
+        "i" => Some(Tag::Open(TagKind::Italic, None)),
+        "/b" => Some(Tag::Close(TagKind::Bold)),
+        "/i" => Some(Tag::Close(TagKind::Italic)),
+        "/color" => Some(Tag::Close(TagKind::Color)),
+        tag => {
+            let hex = tag
+                .strip_prefix("color=")?
+                .trim_matches(|c| c == '"' || c == '\'');
+            let color = Srgba::hex(hex).ok()?;
+            Some(Tag::Open(TagKind::Color, Some(color.into())))
+        }
+    }
+}
+
+/// Appends the text to the last run when it has the same style
+fn push_run(runs: &mut Vec<(String, MarkupStyle)>, text: &str, style: &MarkupStyle) {
+    if text.is_empty() {
+        return;
+    }
+    match runs.last_mut() {
+        Some((run, run_style)) if run_style == style => run.push_str(text),
+        _ => runs.push((text.to_string(), style.clone())),
+    }
+}
+
+/// Text of a translation without its markup tags
+pub(crate) fn strip_markup(text: &str) -> String {
+    parse_markup(text).map_or_else(
+        || text.to_string(),
+        |runs| runs.into_iter().map(|(run, _)| run).collect(),
+    )
+}
@@ -0,0 +1,195 @@
+/// Native names of the most common locales, sorted by locale
+///
+/// Region or script specific entries take precedence over the language ones, `zh-TW` -> `繁體中文`, `zh` -> `中文`
+const NATIVE_NAMES: &[(&str, &str)] = &[
+    ("af", "Afrikaans"),
+    ("am", "አማርኛ"),
+    ("ar", "العربية"),
+    ("az", "Azərbaycan"),
+    ("be", "Беларуская"),
+    ("bg", "Български"),
+    ("bn", "বাংলা"),
+    ("bs", "Bosanski"),
+    ("ca", "Català"),
+    ("cs", "Čeština"),
+    ("cy", "Cymraeg"),
+    ("da", "Dansk"),
+    ("de", "Deutsch"),
+    ("el", "Ελληνικά"),
+    ("en", "English"),
+    ("en-GB", "English (UK)"),
+    ("en-US", "English (US)"),
+    ("eo", "Esperanto"),
+    ("es", "Español"),
+    ("es-419", "Español (Latinoamérica)"),
+    ("et", "Eesti"),
+    ("eu", "Euskara"),
+    ("fa", "فارسی"),
+    ("fi", "Suomi"),
+    ("fil", "Filipino"),
+    ("fr", "Français"),
+    ("fr-CA", "Français (Canada)"),
+    ("ga", "Gaeilge"),
+    ("gl", "Galego"),
+    ("gu", "ગુજરાતી"),
+    ("he", "עברית"),
+    ("hi", "हिन्दी"),
+    ("hr", "Hrvatski"),
+    ("hu", "Magyar"),
+    ("hy", "Հայերեն"),
+    ("id", "Bahasa Indonesia"),
+    ("is", "Íslenska"),
+    ("it", "Italiano"),
+    ("ja", "日本語"),
+    ("ka", "ქართული"),
+    ("kk", "Қазақ"),
+    ("km", "ខ្មែរ"),
+    ("kn", "ಕನ್ನಡ"),
+    ("ko", "한국어"),
+    ("lo", "ລາວ"),
+    ("lt", "Lietuvių"),
+    ("lv", "Latviešu"),
+    ("mk", "Македонски"),
+    ("ml", "മലയാളം"),
+    ("mn", "Монгол"),
+    ("mr", "मराठी"),
+    ("ms", "Bahasa Melayu"),
+    ("my", "မြန်မာ"),
+    ("nb", "Norsk bokmål"),
+    ("ne", "नेपाली"),
+    ("nl", "Nederlands"),
+    ("nn", "Norsk nynorsk"),
+    ("no", "Norsk"),
+    ("pa", "ਪੰਜਾਬੀ"),
+    ("pl", "Polski"),
+    ("pt", "Português"),
+    ("pt-BR", "Português (Brasil)"),
+    ("pt-PT", "Português (Portugal)"),
+    ("ro", "Română"),
+    ("ru", "Русский"),
+    ("si", "සිංහල"),
+    ("sk", "Slovenčina"),
+    ("sl", "Slovenščina"),
+    ("sq", "Shqip"),
+    ("sr", "Српски"),
+    ("sr-Latn", "Srpski"),
+    ("sv", "Svenska"),
+    ("sw", "Kiswahili"),
+    ("ta", "தமிழ்"),
+    ("te", "తెలుగు"),
+    ("th", "ไทย"),
+    ("tr", "Türkçe"),
+    ("uk", "Українська"),
+    ("ur", "اردو"),
+    ("uz", "Oʻzbek"),
+    ("vi", "Tiếng Việt"),
+    ("zh", "中文"),
+    ("zh-CN", "简体中文"),
+    ("zh-Hans", "简体中文"),
+    ("zh-Hant", "繁體中文"),
+    ("zh-HK", "繁體中文（香港）"),
+    ("zh-TW", "繁體中文"),
+    ("zu", "isiZulu"),
+];
+
+/// Most likely scripts of the languages that aren't written in the Latin script, from the CLDR likely subtags, sorted by locale
+///
+/// Region specific entries take precedence over the language ones, `zh-TW` -> `Hant`, `zh` -> `Hans`
+const SCRIPTS: &[(&str, &str)] = &[
+    ("am", "Ethi"),
+    ("ar", "Arab"),
+    ("be", "Cyrl"),
+    ("bg", "Cyrl"),
+    ("bn", "Beng"),
+    ("ckb", "Arab"),
+    ("dv", "Thaa"),
+    ("el", "Grek"),
+    ("fa", "Arab"),
+    ("gu", "Gujr"),
+    ("he", "Hebr"),
+    ("hi", "Deva"),
+    ("hy", "Armn"),
+    ("iw", "Hebr"),
+    ("ja", "Jpan"),
+    ("ka", "Geor"),
+    ("kk", "Cyrl"),
+    ("km", "Khmr"),
+    ("kn", "Knda"),
+    ("ko", "Kore"),
+    ("ks", "Arab"),
+    ("lo", "Laoo"),
+    ("mk", "Cyrl"),
+    ("ml", "Mlym"),
+    ("mn", "Cyrl"),
+    ("mr", "Deva"),
+    ("my", "Mymr"),
+    ("ne", "Deva"),
+    ("nqo", "Nkoo"),
+    ("pa", "Guru"),
+    ("ps", "Arab"),
+    ("ru", "Cyrl"),
+    ("sd", "Arab"),
+    ("si", "Sinh"),
+    ("sr", "Cyrl"),
+    ("syr", "Syrc"),
+    ("ta", "Taml"),
+    ("te", "Telu"),
+    ("th", "Thai"),
+    ("ug", "Arab"),
+    ("uk", "Cyrl"),
+    ("ur", "Arab"),
+    ("yi", "Hebr"),
+    ("zh", "Hans"),
+    ("zh-HK", "Hant"),
+    ("zh-MO", "Hant"),
+    ("zh-TW", "Hant"),
+];
+
+/// Finds the script of a locale, either its script subtag or the most likely one of its language and region,
+/// `sr-Latn` -> `Latn`, `ru` -> `Cyrl`
+///
+/// The languages of the [NATIVE_NAMES] that aren't in the [SCRIPTS] are written in the Latin script,
+/// `None` for the other languages
+pub(crate) fn script(locale: &str) -> Option<String> {
+    let locale = locale.replace('_', "-").parse::<icu_locid::Locale>().ok()?;
+    if let Some(script) = locale.id.script {
+        return Some(script.to_string());
+    }
+    let language = locale.id.language.as_str();
+    let region = locale
+        .id
+        .region
+        .map(|region| format!("{}-{}", language, region));
+    let script = region
+        .iter()
+        .map(String::as_str)
+        .chain([language])
+        .find_map(|code| {
+            SCRIPTS
+                .iter()
+                .find(|(c, _)| c.eq_ignore_ascii_case(code))
+                .map(|(_, script)| script.to_string())
+        })
+        .or_else(|| {
+            NATIVE_NAMES
+                .iter()
+                .any(|(code, _)| *code == language)
+                .then(|| "Latn".to_string())
+        });
+    script
+}
+
+/// Finds the native name of a locale, trying its less specific variants, `pt-BR` -> `pt`
+pub(crate) fn native_name(locale: &str) -> Option<&'static str> {
+    let locale = locale.replace('_', "-");
+    let mut current = locale.as_str();
+    loop {
+        if let Some((_, name)) = NATIVE_NAMES
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(current))
+        {
+            return Some(name);
+        }
+        current = &current[..current.rfind('-')?];
+    }
+}
@@ -0,0 +1,99 @@
+//! Placeholders of the translations collected by the build script, checked against the arguments of the [crate::t] macro at compile time
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/bevy_simple_i18n_placeholders.rs"
+));
+
+/// Capacity of the error messages, longer ones are truncated
+const MESSAGE_CAPACITY: usize = 256;
+
+/// Fails the const evaluation when the arguments don't exactly cover the placeholders of the key in every locale,
+/// e.g. a missing `name` for `Hello {name}` or a misspelled `naem`
+#[doc(hidden)]
+pub const fn check_args(key: &str, args: &[&str]) {
+    let placeholders = placeholders(key);
+    let mut i = 0;
+    while i < placeholders.len() {
+        if !contains(args, placeholders[i]) {
+            fail(&[
+                "Missing argument ",
+                placeholders[i],
+                " for the translation key ",
+                key,
+            ]);
+        }
+        i += 1;
+    }
+    let mut i = 0;
+    while i < args.len() {
+        if !contains(placeholders, args[i]) {
+            fail(&[
+                "Argument ",
+                args[i],
+                " has no placeholder in the translation key ",
+                key,
+            ]);
+        }
+        i += 1;
+    }
+}
+
+const fn placeholders(key: &str) -> &'static [&'static str] {
+    let mut i = 0;
+    while i < PLACEHOLDERS.len() {
+        if eq(PLACEHOLDERS[i].0, key) {
+            return PLACEHOLDERS[i].1;
+        }
+        i += 1;
+    }
+    &[]
+}
+
+const fn contains(names: &[&str], name: &str) -> bool {
+    let mut i = 0;
+    while i < names.len() {
+        if eq(names[i], name) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+const fn eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Panics with the concatenated parts, const panics only accept a single string argument
+const fn fail(parts: &[&str]) -> ! {
+    let mut buffer = [0u8; MESSAGE_CAPACITY];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let bytes = parts[i].as_bytes();
+        let mut j = 0;
+        while j < bytes.len() && len < MESSAGE_CAPACITY {
+            buffer[len] = bytes[j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    let (message, _) = buffer.split_at(len);
+    match core::str::from_utf8(message) {
+        Ok(message) => panic!("{}", message),
+        Err(_) => panic!("Arguments don't match the placeholders of the translation key"),
+    }
+}
@@ -1,124 +1,2255 @@
-use std::path::Path;
+#[cfg(feature = "fonts")]
+use std::time::Duration;
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
 
 use bevy::{
     app::{Plugin, PreStartup, Update},
     asset::{AssetServer, Handle},
+    color::Color,
     ecs::{
-        schedule::{
-            common_conditions::{resource_changed, resource_exists},
-            IntoSystemConfigs,
-        },
-        system::{Commands, Query, Res, ResMut},
+        change_detection::{DetectChanges, Ref},
+        component::Component,
+        entity::Entity,
+        event::{EventReader, EventWriter},
+        query::{Added, Changed, Has, Or, QueryItem, With, Without},
+        schedule::{common_conditions::resource_changed, IntoSystemConfigs, SystemSet},
+        system::{Commands, Local, ParallelCommands, ParamSet, Query, Res, ResMut, SystemParam},
+        world::Mut,
+    },
+    hierarchy::{BuildChildren, Children, DespawnRecursiveExt, Parent},
+    sprite::Sprite,
+    text::{Font, Text2d, TextColor, TextFont, TextSpan, TextSpanAccess},
+    ui::{
+        widget::{ImageNode, Text},
+        AlignContent, AlignItems, AlignSelf, FlexDirection, JustifyContent, JustifyItems,
+        JustifySelf, Node,
     },
-    prelude::{resource_removed, Without},
-    text::{Font, TextFont},
-    ui::widget::Text,
 };
+#[cfg(feature = "fonts")]
+use bevy::{
+    asset::{Assets, LoadState},
+    ecs::{
+        change_detection::DetectChangesMut,
+        schedule::common_conditions::{on_event, resource_exists},
+    },
+    text::TextLayoutInfo,
+    time::{Real, Time},
+    ui::PositionType,
+};
+
+#[cfg(any(feature = "fonts", feature = "runtime_translations"))]
+use bevy::asset::AssetApp;
+#[cfg(feature = "observers")]
+use bevy::ecs::{
+    component::Tick, entity::EntityHashMap, observer::Trigger, system::SystemChangeTick,
+    world::OnInsert,
+};
+#[cfg(feature = "states")]
+use bevy::state::{
+    condition::in_state,
+    state::{FreelyMutableState, NextState},
+};
+#[cfg(feature = "runtime_translations")]
+use bevy::utils::hashbrown::HashMap;
 
+#[cfg(feature = "runtime_translations")]
+use crate::assets::{LocaleFile, LocaleFileLoader};
+#[cfg(feature = "fonts")]
 use crate::{
-    components::{I18nFont, I18nNumber, I18nText},
-    resources::{FontFolder, FontManager, FontsLoading, I18n},
+    components::{I18nFont, TextSpacing},
+    events::{FontLoadingProgress, FontsLoaded},
+    markup::MarkupStyle,
+    resources::{
+        is_locale_of, FontFolder, FontManager, FontStyle, FontsLoading, GlyphWarmup, I18nConfig,
+    },
+};
+use crate::{
+    components::{
+        I18nImage, I18nNumber, I18nPlaceholder, I18nString, I18nText, MirrorLayout, NoMirror,
+        StringTarget, TextPlaceholder,
+    },
+    events::{DirectionChanged, LocaleChanged, RefreshTranslations},
+    markup::{parse_markup, strip_markup},
+    resources::{
+        ActiveLocale, Delimiters, I18n, LayoutDirection, MissingKeyBehavior, TextDirection,
+    },
 };
 
+#[cfg(feature = "fonts")]
 include!(concat!(env!("OUT_DIR"), "/bevy_simple_i18n.rs"));
 
 /// Initializes the `bevy_simple_i18n` plugin
 ///
 /// # Example
-/// ```
+/// ```no_run
 /// use bevy::prelude::*;
 /// use bevy_simple_i18n::prelude::*;
 ///
 /// fn main() {
 ///     App::new()
-///         .add_plugins(I18nPlugin)
+///         .add_plugins(
+///             I18nPlugin::new()
+///                 .with_fonts_path("localization/fonts")
+///                 .with_default_locale("fr"),
+///         )
 ///         .run();
 /// }
 /// ```
-pub struct I18nPlugin;
+#[derive(Debug, Clone, Default)]
+pub struct I18nPlugin {
+    /// Asset folder that the dynamic font families are loaded from, `None` to load every discovered family
+    #[cfg(feature = "fonts")]
+    fonts_path: Option<String>,
+    /// Locale to initialize the [I18n] resource with, `None` to keep the `rust-i18n` default
+    default_locale: Option<String>,
+    /// Ordered fallback chain used when a key is missing in the active locale
+    fallback_locales: Vec<String>,
+    /// Resolves the locale of the platform that the app was built for, taking precedence over the default locale
+    platform_locale: Option<fn() -> Option<String>>,
+    /// Whether the locale of the operating system should be used when it's available
+    detect_system_locale: bool,
+    /// What to return for keys that are missing in the active locale and all of its fallbacks
+    missing_key_behavior: MissingKeyBehavior,
+    /// Delimiters of the interpolation placeholders of the translations
+    delimiters: Delimiters,
+    /// Asset folder that the locale files are loaded from at runtime, `None` to only use the embedded translations
+    #[cfg(feature = "runtime_translations")]
+    translations_path: Option<String>,
+    /// Extra asset folders of locale files merged over the `translations_path`, in order of precedence
+    #[cfg(feature = "runtime_translations")]
+    translation_roots: Vec<String>,
+    /// File that the selected locale is saved to and restored from, `None` to not persist it
+    persisted_locale_path: Option<PathBuf>,
+    /// Whether the fonts of a locale are only loaded once it's selected
+    #[cfg(feature = "fonts")]
+    lazy_fonts: bool,
+    /// Whether the fonts of the locales that are switched away from are unloaded
+    #[cfg(feature = "fonts")]
+    unload_fonts: bool,
+    /// Font sizes that the glyph atlas is warmed up with for the active locale, empty to not warm it up
+    #[cfg(feature = "fonts")]
+    glyph_warmup: Vec<f32>,
+    /// How long a font loading phase can last before it ends without the pending fonts, `None` to wait for them
+    #[cfg(feature = "fonts")]
+    font_loading_timeout: Option<Duration>,
+    /// Registers the transition of the app state out of the loading phase, see [I18nPlugin::with_loading_state]
+    #[cfg(feature = "states")]
+    loading_state: Option<LoadingState>,
+    /// Registers the systems of the input widgets whose placeholder is localized, see [I18nPlugin::with_placeholder_widget]
+    placeholder_widgets: Vec<PlaceholderWidget>,
+    /// Registers the systems of the components whose string is localized, see [I18nPlugin::with_string_target]
+    string_targets: Vec<StringTargetSystem>,
+    /// Number of text entities from which they're translated in parallel, `None` for [PARALLEL_TRANSLATIONS_THRESHOLD]
+    parallel_threshold: Option<usize>,
+}
+
+/// Registers the system of an input widget on the app, type erased so the plugin isn't generic over the widget
+#[derive(Clone)]
+struct PlaceholderWidget(std::sync::Arc<dyn Fn(&mut bevy::prelude::App) + Send + Sync>);
+
+impl std::fmt::Debug for PlaceholderWidget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PlaceholderWidget")
+    }
+}
+
+/// Registers the system of a string target on the app, type erased so the plugin isn't generic over the component
+#[derive(Clone)]
+struct StringTargetSystem(std::sync::Arc<dyn Fn(&mut bevy::prelude::App) + Send + Sync>);
+
+impl std::fmt::Debug for StringTargetSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StringTargetSystem")
+    }
+}
+
+/// Registers the systems of a loading state on the app, type erased so the plugin isn't generic over the state
+#[cfg(feature = "states")]
+#[derive(Clone)]
+struct LoadingState(std::sync::Arc<dyn Fn(&mut bevy::prelude::App) + Send + Sync>);
+
+#[cfg(feature = "states")]
+impl std::fmt::Debug for LoadingState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LoadingState")
+    }
+}
+
+impl I18nPlugin {
+    /// Creates a new `I18nPlugin` with the default configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only load the dynamic font families found in this folder, relative to the assets folder
+    #[cfg(feature = "fonts")]
+    pub fn with_fonts_path(mut self, path: impl Into<String>) -> Self {
+        self.fonts_path = Some(path.into());
+        self
+    }
+
+    /// Set the locale that is active when the app starts
+    pub fn with_default_locale(mut self, locale: impl Into<String>) -> Self {
+        self.default_locale = Some(locale.into());
+        self
+    }
+
+    /// Set the ordered fallback chain used when a key is missing in the active locale
+    ///
+    /// Can also be changed at runtime with [I18n::set_fallbacks]
+    pub fn with_fallback_locales(
+        mut self,
+        locales: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.fallback_locales = locales.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set what to return for keys that are missing in the active locale and all of its fallbacks
+    ///
+    /// Can also be changed at runtime with [I18n::set_missing_key_behavior]
+    pub fn with_missing_key_behavior(mut self, behavior: MissingKeyBehavior) -> Self {
+        self.missing_key_behavior = behavior;
+        self
+    }
+
+    /// Translate the texts in parallel when every one of them is updated and there are at least this many, 2,000 by default
+    ///
+    /// `0` always translates them in parallel and `usize::MAX` never does
+    pub fn with_parallel_threshold(mut self, count: usize) -> Self {
+        self.parallel_threshold = Some(count);
+        self
+    }
+
+    /// Set the delimiters of the interpolation placeholders, `{name}` by default
+    ///
+    /// Can also be changed at runtime with [I18n::set_delimiters]
+    pub fn with_delimiters(mut self, delimiters: Delimiters) -> Self {
+        self.delimiters = delimiters;
+        self
+    }
+
+    /// Load the locale files of this folder, relative to the assets folder, as assets at runtime
+    ///
+    /// The loaded translations take precedence over the embedded ones, which are still used for the keys
+    /// and locales that fail to load. Defaults to `locales` with the `hot_reload` and `external_translations` features
+    #[cfg(feature = "runtime_translations")]
+    pub fn with_runtime_translations(mut self, path: impl Into<String>) -> Self {
+        self.translations_path = Some(path.into());
+        self
+    }
+
+    /// Also load the locale files of this folder at runtime, e.g. the translations of a DLC or a mod
+    ///
+    /// Its keys override the ones of the runtime translations and of the folders added before it,
+    /// the other keys keep their translation
+    #[cfg(feature = "runtime_translations")]
+    pub fn with_translation_root(mut self, path: impl Into<String>) -> Self {
+        self.translation_roots.push(path.into());
+        self
+    }
+
+    /// Start in the locale returned by the resolver when it's one of the available locales,
+    /// e.g. Japanese on the Japanese console SKU and English on the global PC build
+    ///
+    /// The resolver runs at `PreStartup`, the default locale is kept when it returns `None` or an unavailable locale.
+    /// It takes precedence over the default locale but yields to [I18nPlugin::detect_system_locale]
+    ///
+    /// ```no_run
+    /// # use bevy_simple_i18n::prelude::*;
+    /// I18nPlugin::new().with_platform_locale(|| match std::env::consts::OS {
+    ///     "macos" | "ios" => Some("ja".to_string()),
+    ///     _ => None,
+    /// });
+    /// ```
+    pub fn with_platform_locale(mut self, resolver: fn() -> Option<String>) -> Self {
+        self.platform_locale = Some(resolver);
+        self
+    }
+
+    /// Start in the best available locale of the preferred languages of the operating system, see [I18n::negotiate]
+    ///
+    /// A region locale that isn't available resolves to its base language, e.g. `de-AT` -> `de`,
+    /// the default locale is kept when none of them are available
+    pub fn detect_system_locale(mut self) -> Self {
+        self.detect_system_locale = true;
+        self
+    }
+
+    /// Only load the fonts of the active locale at startup, the fonts of the other locales are loaded once they're selected
+    ///
+    /// The `FontsLoading` phase starts again while they load, fallback fonts are always loaded.
+    /// Useful for projects with many large fonts, since every font is loaded at startup by default
+    #[cfg(feature = "fonts")]
+    pub fn with_lazy_fonts(mut self) -> Self {
+        self.lazy_fonts = true;
+        self
+    }
+
+    /// Unload the fonts of the previous locale after switching locales, e.g. on memory constrained platforms
+    ///
+    /// The fonts of the active locale, its fallbacks, the pinned texts and the fallback fonts are kept.
+    /// Unloaded fonts are loaded again once their locale is selected, like [I18nPlugin::with_lazy_fonts]
+    #[cfg(feature = "fonts")]
+    pub fn with_font_unloading(mut self) -> Self {
+        self.unload_fonts = true;
+        self
+    }
+
+    /// Render every character of the translations of the active locale at these font sizes while the fonts load,
+    /// so the glyphs are already in the atlas the first time they're shown
+    #[cfg(feature = "fonts")]
+    pub fn with_glyph_warmup(mut self, font_sizes: impl IntoIterator<Item = f32>) -> Self {
+        self.glyph_warmup = font_sizes.into_iter().collect();
+        self
+    }
+
+    /// End a `FontsLoading` phase after this long even if some fonts are still loading,
+    /// e.g. on platforms where an asset can silently never resolve
+    ///
+    /// The pending fonts are logged and removed from their family, so the texts use the fonts that loaded
+    /// and the fallback fonts instead of the app waiting for them forever. Disabled by default
+    #[cfg(feature = "fonts")]
+    pub fn with_font_loading_timeout(mut self, timeout: Duration) -> Self {
+        self.font_loading_timeout = Some(timeout);
+        self
+    }
+
+    /// Drive the app state from `loading` to `ready` once the dynamic fonts and the runtime translations are loaded
+    ///
+    /// The state has to be initialized by the app, e.g. with `init_state`
+    #[cfg(feature = "states")]
+    pub fn with_loading_state<S: FreelyMutableState>(mut self, loading: S, ready: S) -> Self {
+        self.loading_state = Some(LoadingState(std::sync::Arc::new(move |app| {
+            app.insert_resource(ReadyState(ready.clone())).add_systems(
+                Update,
+                finish_loading::<S>
+                    .after(I18nSet)
+                    .run_if(in_state(loading.clone())),
+            );
+        })));
+        self
+    }
+
+    /// Localize the placeholder of the input widgets `W` with the [I18nPlaceholder] of their entity,
+    /// can be called once for each kind of widget, see [TextPlaceholder]
+    pub fn with_placeholder_widget<W: TextPlaceholder>(mut self) -> Self {
+        self.placeholder_widgets
+            .push(PlaceholderWidget(std::sync::Arc::new(|app| {
+                app.add_systems(Update, update_placeholders::<W>.in_set(I18nSet));
+            })));
+        self
+    }
+
+    /// Localize a string field of the components `T` with the [I18nString] of their entity,
+    /// can be called once for each kind of component, see [StringTarget]
+    ///
+    /// The title of a `Window` is localized without registering it with the `window` feature
+    pub fn with_string_target<T: StringTarget>(mut self) -> Self {
+        self.string_targets
+            .push(StringTargetSystem(std::sync::Arc::new(|app| {
+                app.add_systems(Update, update_string_targets::<T>.in_set(I18nSet));
+            })));
+        self
+    }
+
+    /// Save the locale to this file whenever it changes, and restore it when the app starts
+    ///
+    /// A saved locale that's no longer available is replaced by the system or default locale
+    pub fn with_persisted_locale(mut self, path: impl Into<PathBuf>) -> Self {
+        self.persisted_locale_path = Some(path.into());
+        self
+    }
+
+    /// Registers the resources and systems of the dynamic fonts, once the locale of the app is set
+    #[cfg(feature = "fonts")]
+    fn build_fonts(&self, app: &mut bevy::prelude::App) {
+        app.insert_resource(I18nConfig {
+            fonts_path: self.fonts_path.clone(),
+            lazy_fonts: self.lazy_fonts,
+            font_loading_timeout: self.font_loading_timeout,
+        })
+        .init_asset_loader::<crate::font_collection::FontCollectionLoader>()
+        .init_resource::<FontManager>()
+        .init_resource::<FontsLoading>()
+        .add_event::<FontsLoaded>()
+        .add_event::<FontLoadingProgress>()
+        .add_systems(
+            PreStartup,
+            load_dynamic_fonts
+                .after(apply_platform_locale)
+                .after(apply_system_locale)
+                .after(load_persisted_locale),
+        )
+        .add_systems(
+            Update,
+            monitor_font_loading
+                .run_if(resource_exists::<FontsLoading>)
+                .in_set(I18nSet)
+                .before(update_translations::<Text>)
+                .before(update_translations::<Text2d>)
+                .before(update_translations::<TextSpan>),
+        );
+
+        if self.lazy_fonts || self.unload_fonts {
+            app.add_systems(Update, load_lazy_fonts.before(I18nSet));
+        }
+
+        if self.unload_fonts {
+            app.add_systems(Update, unload_inactive_fonts.after(I18nSet));
+        }
+
+        if !self.glyph_warmup.is_empty() {
+            app.insert_resource(GlyphWarmup {
+                font_sizes: self.glyph_warmup.clone(),
+                ..Default::default()
+            })
+            .add_systems(
+                Update,
+                (
+                    warm_glyph_atlas
+                        .run_if(resource_exists::<FontsLoading>)
+                        .before(monitor_font_loading),
+                    restart_glyph_warmup
+                        .run_if(on_event::<LocaleChanged>)
+                        .after(I18nSet),
+                ),
+            );
+        }
+    }
+}
+
+/// System set containing every system that updates the translated text entities
+///
+/// Useful for ordering your own systems before or after the translations are applied
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct I18nSet;
 
 impl Plugin for I18nPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.init_resource::<I18n>()
-            .init_resource::<FontManager>()
-            .init_resource::<FontsLoading>()
-            .add_systems(PreStartup, load_dynamic_fonts)
+        let mut i18n = I18n::default();
+        if let Some(locale) = self.default_locale.as_ref() {
+            if let Err(err) = i18n.set_locale(locale) {
+                bevy::log::error!("Could not set the default locale: {}", err);
+            }
+        }
+        i18n.set_fallbacks(self.fallback_locales.iter().cloned());
+        i18n.set_missing_key_behavior(self.missing_key_behavior.clone());
+        i18n.set_delimiters(self.delimiters.clone());
+
+        app.insert_resource(i18n)
+            .init_resource::<ActiveLocale>()
+            .init_resource::<LayoutDirection>()
+            .insert_resource(ParallelThreshold(
+                self.parallel_threshold
+                    .unwrap_or(PARALLEL_TRANSLATIONS_THRESHOLD),
+            ))
+            .add_event::<LocaleChanged>()
+            .add_event::<DirectionChanged>()
+            .add_event::<RefreshTranslations>()
+            .add_systems(
+                PreStartup,
+                init_layout_direction
+                    .after(apply_platform_locale)
+                    .after(apply_system_locale)
+                    .after(load_persisted_locale),
+            )
             .add_systems(
                 Update,
                 (
-                    monitor_font_loading.run_if(resource_exists::<FontsLoading>),
-                    update_translations.run_if(resource_removed::<FontsLoading>),
-                    update_translations.run_if(resource_changed::<I18n>),
-                ),
+                    (
+                        emit_locale_changed.run_if(resource_changed::<I18n>),
+                        update_layout_direction,
+                        (
+                            update_translations::<Text>,
+                            update_translations::<Text2d>,
+                            update_translations::<TextSpan>,
+                            mirror_layouts,
+                        ),
+                    )
+                        .chain(),
+                    update_images,
+                )
+                    .in_set(I18nSet),
+            );
+
+        #[cfg(feature = "fonts")]
+        self.build_fonts(app);
+
+        #[cfg(feature = "observers")]
+        add_translation_observers::<Text>(app);
+        #[cfg(feature = "observers")]
+        add_translation_observers::<Text2d>(app);
+        #[cfg(feature = "observers")]
+        add_translation_observers::<TextSpan>(app);
+
+        #[cfg(feature = "runtime_translations")]
+        let folders: Vec<(String, Handle<bevy::asset::LoadedFolder>)> = self
+            .translations_path
+            .clone()
+            .or_else(|| {
+                cfg!(any(
+                    feature = "hot_reload",
+                    feature = "external_translations"
+                ))
+                .then(|| "locales".to_string())
+            })
+            .into_iter()
+            .chain(self.translation_roots.iter().cloned())
+            .map(|path| (path, Handle::default()))
+            .collect();
+        #[cfg(feature = "runtime_translations")]
+        if !folders.is_empty() {
+            app.init_asset::<LocaleFile>()
+                .register_asset_loader(LocaleFileLoader {
+                    roots: folders.iter().map(|(path, _)| path.clone()).collect(),
+                })
+                .insert_resource(LocaleFolders(folders))
+                .add_systems(PreStartup, load_locale_files)
+                .add_systems(
+                    Update,
+                    (log_locale_file_errors, reload_locale_files).before(I18nSet),
+                );
+        }
+
+        #[cfg(feature = "audio")]
+        app.add_systems(Update, update_audio.in_set(I18nSet));
+
+        if let Some(resolver) = self.platform_locale {
+            app.insert_resource(PlatformLocale(resolver)).add_systems(
+                PreStartup,
+                apply_platform_locale.before(apply_system_locale),
+            );
+        }
+
+        if self.detect_system_locale {
+            app.add_systems(PreStartup, apply_system_locale);
+        }
+
+        #[cfg(feature = "missing_key_log")]
+        app.init_resource::<crate::resources::MissingKeyLog>()
+            .add_systems(bevy::app::Last, collect_missing_keys);
+
+        if let Some(path) = self.persisted_locale_path.clone() {
+            app.insert_resource(PersistedLocale(path))
+                .add_systems(
+                    PreStartup,
+                    load_persisted_locale
+                        .after(apply_platform_locale)
+                        .after(apply_system_locale),
+                )
+                .add_systems(Update, save_persisted_locale.after(I18nSet));
+        }
+
+        for widget in &self.placeholder_widgets {
+            (widget.0)(app);
+        }
+        #[cfg(feature = "window")]
+        app.add_systems(
+            Update,
+            update_string_targets::<bevy::window::Window>.in_set(I18nSet),
+        );
+        for target in &self.string_targets {
+            (target.0)(app);
+        }
+        #[cfg(feature = "accessibility")]
+        app.add_systems(Update, update_accessibility_nodes.in_set(I18nSet));
+
+        #[cfg(feature = "states")]
+        if let Some(loading_state) = self.loading_state.as_ref() {
+            (loading_state.0)(app);
+        }
+    }
+}
+
+/// State that the app moves to once the loading phase is over, see [I18nPlugin::with_loading_state]
+#[cfg(feature = "states")]
+#[derive(bevy::ecs::system::Resource)]
+struct ReadyState<S: FreelyMutableState>(S);
+
+/// Moves the app state to the [ReadyState] once the dynamic fonts and the runtime translations are loaded,
+/// the locale folders that fail to load count as loaded since their keys use the embedded translations
+#[cfg(feature = "states")]
+fn finish_loading<S: FreelyMutableState>(
+    ready: Res<ReadyState<S>>,
+    mut next_state: ResMut<NextState<S>>,
+    #[cfg(feature = "fonts")] fonts_loading: Option<Res<FontsLoading>>,
+    #[cfg(feature = "runtime_translations")] folders: Option<Res<LocaleFolders>>,
+    #[cfg(feature = "runtime_translations")] asset_server: Res<AssetServer>,
+) {
+    #[cfg(feature = "fonts")]
+    if fonts_loading.is_some() {
+        return;
+    }
+    #[cfg(feature = "runtime_translations")]
+    if folders.is_some_and(|folders| {
+        folders.0.iter().any(|(_, handle)| {
+            !matches!(
+                asset_server.recursive_dependency_load_state(handle),
+                bevy::asset::RecursiveDependencyLoadState::Loaded
+                    | bevy::asset::RecursiveDependencyLoadState::Failed(_)
+            )
+        })
+    }) {
+        return;
+    }
+    bevy::log::debug!("Translations and fonts loaded, leaving the loading state");
+    next_state.set(ready.0.clone());
+}
+
+/// Folders of the locale files that are loaded at runtime, and watched for changes with the `hot_reload` feature,
+/// in order of precedence along with the handle that keeps their files loaded
+#[cfg(feature = "runtime_translations")]
+#[derive(bevy::ecs::system::Resource)]
+struct LocaleFolders(Vec<(String, Handle<bevy::asset::LoadedFolder>)>);
+
+/// Loads the locale files as assets
+#[cfg(feature = "runtime_translations")]
+fn load_locale_files(mut folders: ResMut<LocaleFolders>, asset_server: Res<AssetServer>) {
+    for (path, handle) in folders.0.iter_mut() {
+        bevy::log::debug!("Loading locale files from {}", path);
+        *handle = asset_server.load_folder(path.clone());
+    }
+}
+
+/// Logs the locale files that failed to load, their keys keep using the embedded translations
+#[cfg(feature = "runtime_translations")]
+fn log_locale_file_errors(
+    mut events: bevy::ecs::event::EventReader<bevy::asset::AssetLoadFailedEvent<LocaleFile>>,
+) {
+    for event in events.read() {
+        bevy::log::error!(
+            "Failed to load locale file {}, falling back to the embedded translations: {}",
+            event.path,
+            event.error
+        );
+    }
+}
+
+/// Rebuilds the runtime translations of the [I18n] resource whenever a locale file or folder is loaded or modified,
+/// which in turn re-runs [update_translations]
+///
+/// The files of the later folders override the keys of the earlier ones
+#[cfg(feature = "runtime_translations")]
+fn reload_locale_files(
+    mut events: bevy::ecs::event::EventReader<bevy::asset::AssetEvent<LocaleFile>>,
+    mut folder_events: bevy::ecs::event::EventReader<
+        bevy::asset::AssetEvent<bevy::asset::LoadedFolder>,
+    >,
+    folders: Res<LocaleFolders>,
+    loaded_folders: Res<bevy::asset::Assets<bevy::asset::LoadedFolder>>,
+    locale_files: Res<bevy::asset::Assets<LocaleFile>>,
+    mut i18n: ResMut<I18n>,
+) {
+    if events.is_empty() && folder_events.is_empty() {
+        return;
+    }
+    events.clear();
+    folder_events.clear();
+
+    let mut translations: HashMap<String, HashMap<String, String>> = HashMap::new();
+    for (_, handle) in folders.0.iter() {
+        let Some(folder) = loaded_folders.get(handle) else {
+            continue;
+        };
+        let mut files: Vec<_> = folder
+            .handles
+            .iter()
+            .filter_map(|handle| {
+                let id = handle.id().try_typed::<LocaleFile>().ok()?;
+                Some((handle.path()?.to_string(), locale_files.get(id)?))
+            })
+            .collect();
+        files.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (_, file) in files {
+            for (locale, table) in file.0.iter() {
+                translations
+                    .entry(locale.clone())
+                    .or_default()
+                    .extend(table.iter().map(|(k, v)| (k.clone(), v.clone())));
+            }
+        }
+    }
+    i18n.set_runtime_translations(translations);
+    i18n.reset_missing_key_warnings();
+}
+
+/// Resolver of the locale of the platform, see [I18nPlugin::with_platform_locale]
+#[derive(bevy::ecs::system::Resource)]
+struct PlatformLocale(fn() -> Option<String>);
+
+/// Sets the locale of the platform on the [I18n] resource if it's available
+fn apply_platform_locale(
+    mut i18n: ResMut<I18n>,
+    mut active_locale: ResMut<ActiveLocale>,
+    platform: Res<PlatformLocale>,
+) {
+    let Some(platform_locale) = (platform.0)() else {
+        bevy::log::debug!("No platform locale, keeping {}", i18n.current());
+        return;
+    };
+    match i18n.find_locale(&platform_locale).map(str::to_string) {
+        Some(locale) if i18n.set_locale(&locale).is_ok() => {
+            bevy::log::debug!("Using platform locale {} as {}", platform_locale, locale);
+            active_locale.0 = locale;
+        }
+        _ => {
+            bevy::log::warn!(
+                "Platform locale {} is not available, keeping {}",
+                platform_locale,
+                i18n.current()
             );
+        }
+    }
+}
+
+/// Sets the best available locale of the preferred ones of the operating system on the [I18n] resource, see [I18n::negotiate]
+fn apply_system_locale(mut i18n: ResMut<I18n>, mut active_locale: ResMut<ActiveLocale>) {
+    let system_locales: Vec<String> = sys_locale::get_locales().collect();
+    if system_locales.is_empty() {
+        bevy::log::debug!(
+            "Could not detect the system locale, keeping {}",
+            i18n.current()
+        );
+        return;
+    }
+    match i18n.negotiate(&system_locales) {
+        Some(locale) if i18n.set_locale(&locale).is_ok() => {
+            bevy::log::debug!("Using system locales {:?} as {}", system_locales, locale);
+            active_locale.0 = locale;
+        }
+        _ => {
+            bevy::log::debug!(
+                "System locales {:?} are not available, keeping {}",
+                system_locales,
+                i18n.current()
+            );
+        }
+    }
+}
+
+/// Moves the missing keys hit during the frame to the [crate::resources::MissingKeyLog]
+#[cfg(feature = "missing_key_log")]
+fn collect_missing_keys(i18n: Res<I18n>, mut log: ResMut<crate::resources::MissingKeyLog>) {
+    let missing_keys = i18n.take_missing_keys();
+    if !missing_keys.is_empty() {
+        log.extend(missing_keys);
+    }
+}
+
+/// File that the selected locale is persisted to
+#[derive(bevy::ecs::system::Resource)]
+struct PersistedLocale(PathBuf);
+
+/// Restores the persisted locale on the [I18n] resource,
+/// overwriting it with the current locale when it's no longer available
+fn load_persisted_locale(
+    mut i18n: ResMut<I18n>,
+    mut active_locale: ResMut<ActiveLocale>,
+    persisted: Res<PersistedLocale>,
+) {
+    let Ok(content) = std::fs::read_to_string(&persisted.0) else {
+        bevy::log::debug!("No persisted locale found at {}", persisted.0.display());
+        return;
+    };
+    let locale = content.trim();
+    match i18n.set_locale(locale) {
+        Ok(()) => {
+            bevy::log::debug!("Using persisted locale {}", locale);
+            active_locale.0 = locale.to_string();
+        }
+        Err(err) => {
+            bevy::log::warn!(
+                "Persisted locale is no longer valid, using {}: {}",
+                i18n.current(),
+                err
+            );
+            write_persisted_locale(&persisted.0, i18n.current());
+        }
+    }
+}
+
+/// Saves the locale to the persisted file whenever it changes
+fn save_persisted_locale(mut events: EventReader<LocaleChanged>, persisted: Res<PersistedLocale>) {
+    if let Some(event) = events.read().last() {
+        write_persisted_locale(&persisted.0, &event.current);
+    }
+}
+
+fn write_persisted_locale(path: &Path, locale: &str) {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        if let Err(err) = std::fs::create_dir_all(parent) {
+            bevy::log::error!("Could not create {}: {}", parent.display(), err);
+            return;
+        }
+    }
+    match std::fs::write(path, locale) {
+        Ok(()) => bevy::log::debug!("Persisted locale {} to {}", locale, path.display()),
+        Err(err) => bevy::log::error!("Could not persist locale to {}: {}", path.display(), err),
     }
 }
 
 /// Loads the dynamic fonts specified in the [FONT_FAMILIES] constant that's generated by the build script
 ///
-/// TODO: Make the loading state more controllable
+/// With lazy fonts, only the fonts of the active locale are loaded, see [load_lazy_fonts]
+#[cfg(feature = "fonts")]
 fn load_dynamic_fonts(
     mut font_manager: ResMut<FontManager>,
     asset_server: Res<bevy::asset::AssetServer>,
+    config: Res<I18nConfig>,
+    i18n: Res<I18n>,
 ) {
     for dyn_font in FONT_FAMILIES.iter() {
+        if let Some(fonts_path) = config.fonts_path.as_ref() {
+            if !Path::new(dyn_font.path).starts_with(fonts_path) {
+                bevy::log::debug!(
+                    "Skipping font family {} outside of {}",
+                    dyn_font.family,
+                    fonts_path
+                );
+                continue;
+            }
+        }
         bevy::log::debug!("Loading dynamic font family: {}", dyn_font.family);
         let mut font_folder = FontFolder::default();
-        font_folder.fallback = asset_server.load(Path::new(dyn_font.path).join("fallback.ttf"));
+        for font in dyn_font.fallbacks.iter() {
+            bevy::log::debug!("Loading fallback font: {}", font);
+            let handler: Handle<Font> = asset_server.load(Path::new(dyn_font.path).join(font));
+            match parse_fallback_file(font) {
+                None | Some(FontStyle::Regular) => font_folder.fallbacks.push(handler),
+                Some(style) => {
+                    font_folder.styled_fallbacks.insert(style, handler);
+                }
+            }
+        }
+        if font_folder.fallbacks.is_empty() {
+            bevy::log::debug!(
+                "Font family {} has no fallback font, missing glyphs won't be replaced",
+                dyn_font.family
+            );
+        }
         for font in dyn_font.locales.iter() {
+            let Some((locale, style)) = parse_font_file(font) else {
+                continue;
+            };
+            if let Some((_, min, max)) = dyn_font.variable.iter().find(|(file, _, _)| file == font)
+            {
+                bevy::log::debug!("Variable font {} covers the weights {}-{}", font, min, max);
+                font_folder
+                    .variable_weights
+                    .insert((locale.to_string(), style), (*min, *max));
+            }
+            let path = Path::new(dyn_font.path)
+                .join(font)
+                .to_string_lossy()
+                .into_owned();
+            if config.lazy_fonts && !is_locale_of(locale, i18n.current()) {
+                bevy::log::debug!("Deferring font: {}", font);
+                font_folder
+                    .unloaded
+                    .insert((locale.to_string(), style), path);
+                continue;
+            }
             bevy::log::debug!("Loading font: {}", font);
-            let locale = font.split('.').next().expect("Locale is required");
-            let path = Path::new(dyn_font.path).join(font);
             let handler: Handle<Font> = asset_server.load(path);
-            font_folder.fonts.insert(locale.to_string(), handler);
+            font_folder
+                .fonts
+                .insert((locale.to_string(), style), handler);
+        }
+        for (locale, scale) in dyn_font.scales.iter() {
+            bevy::log::debug!("Font scale of {} locale: {}", locale, scale);
+            font_folder.scales.insert(locale.to_string(), *scale);
+        }
+        for (locale, line_height, letter_spacing) in dyn_font.spacings.iter() {
+            font_folder.spacings.insert(
+                locale.to_string(),
+                TextSpacing {
+                    line_height: *line_height,
+                    letter_spacing: *letter_spacing,
+                },
+            );
         }
         font_manager.insert(dyn_font.family.to_string(), font_folder);
     }
 }
 
+/// Loads the deferred fonts of the locales that are selected or pinned by a text, when lazy fonts are enabled,
+/// including the texts that get an [I18nFont] after they're spawned
+///
+/// Inserts the [FontsLoading] resource again until they're loaded, then only the texts of their families are translated again
+#[cfg(feature = "fonts")]
+#[allow(clippy::type_complexity)]
+fn load_lazy_fonts(
+    mut commands: Commands,
+    loading: Option<ResMut<FontsLoading>>,
+    i18n: Res<I18n>,
+    texts: Query<&I18nText, Or<(Added<I18nText>, Added<I18nFont>)>>,
+    mut font_manager: ResMut<FontManager>,
+    asset_server: Res<AssetServer>,
+) {
+    let locales: Vec<&str> = i18n
+        .is_changed()
+        .then(|| i18n.current())
+        .into_iter()
+        .chain(texts.iter().filter_map(I18nText::locale))
+        .collect();
+    let is_pending = |folder: &FontFolder| {
+        folder.unloaded.keys().any(|(font_locale, _)| {
+            locales
+                .iter()
+                .any(|locale| is_locale_of(font_locale, locale))
+        })
+    };
+    if !font_manager.fonts.values().any(is_pending) {
+        return;
+    }
+    let mut families = vec![];
+    for (family, folder) in font_manager.fonts.iter_mut() {
+        let pending: Vec<(String, FontStyle)> = folder
+            .unloaded
+            .keys()
+            .filter(|(font_locale, _)| {
+                locales
+                    .iter()
+                    .any(|locale| is_locale_of(font_locale, locale))
+            })
+            .cloned()
+            .collect();
+        if !pending.is_empty() {
+            families.push(family.clone());
+        }
+        for key in pending {
+            let path = folder.unloaded.remove(&key).unwrap();
+            bevy::log::debug!("Loading deferred font: {}", path);
+            folder.fonts.insert(key, asset_server.load(path));
+        }
+    }
+    match loading {
+        // Texts of every family are already translated again when the current loading phase ends
+        Some(mut loading) => {
+            if let Some(loading) = loading.families.as_mut() {
+                loading.extend(families);
+            }
+        }
+        None => commands.insert_resource(FontsLoading {
+            families: Some(families),
+        }),
+    }
+}
+
+/// Unloads the fonts of the locales that are no longer used after the locale changes
+#[cfg(feature = "fonts")]
+fn unload_inactive_fonts(
+    mut events: EventReader<LocaleChanged>,
+    i18n: Res<I18n>,
+    texts: Query<&I18nText>,
+    mut font_manager: ResMut<FontManager>,
+) {
+    if events.read().last().is_none() {
+        return;
+    }
+    let locales: Vec<&str> = std::iter::once(i18n.current())
+        .chain(i18n.fallbacks().iter().map(String::as_str))
+        .chain(texts.iter().filter_map(I18nText::locale))
+        .collect();
+    font_manager.unload_fonts_except(&locales);
+}
+
+/// Splits a font file name into its locale and style, `en.bold.ttf` -> (`en`, [FontStyle::Bold])
+#[cfg(feature = "fonts")]
+fn parse_font_file(font: &str) -> Option<(&str, FontStyle)> {
+    let stem = font.rsplit_once('.').map_or(font, |(stem, _)| stem);
+    let (locale, suffix) = match stem.split_once('.') {
+        Some((locale, suffix)) => (locale, Some(suffix)),
+        None => (stem, None),
+    };
+    let style = FontStyle::from_suffix(suffix);
+    if style.is_none() {
+        bevy::log::warn!("Unknown font style of {}, skipping it", font);
+    }
+    style.map(|style| (locale, style))
+}
+
+/// Style of a fallback font file, `None` for the chain members that are named instead of styled,
+/// `fallback.symbols.ttf`
+#[cfg(feature = "fonts")]
+fn parse_fallback_file(font: &str) -> Option<FontStyle> {
+    let stem = font.rsplit_once('.').map_or(font, |(stem, _)| stem);
+    FontStyle::from_suffix(stem.split_once('.').map(|(_, suffix)| suffix))
+}
+
 /// Monitors the font loading state and removes the [FontsLoading] resource when all fonts are loaded
 ///
-/// TODO: Make the loading state more controllable
+/// Fonts that fail to load are logged and removed from their family, so the text falls back to the other fonts,
+/// as are the fonts still loading once the phase lasts longer than the timeout, see [I18nPlugin::with_font_loading_timeout]
+///
+/// Sends a [FontLoadingProgress] event every frame while loading, then a single [FontsLoaded] event,
+/// see [I18nPlugin::with_loading_state] to drive an app state instead
+#[cfg(feature = "fonts")]
+#[allow(clippy::too_many_arguments)]
 fn monitor_font_loading(
     mut commands: Commands,
-    font_manager: Res<FontManager>,
+    mut font_manager: ResMut<FontManager>,
     asset_server: Res<AssetServer>,
+    mut progress_events: EventWriter<FontLoadingProgress>,
+    mut loaded_events: EventWriter<FontsLoaded>,
+    glyph_warmup: Option<Res<GlyphWarmup>>,
+    loading: Res<FontsLoading>,
+    mut dyn_fonts: Query<&mut I18nFont>,
+    config: Res<I18nConfig>,
+    time: Option<Res<Time<Real>>>,
+    mut started: Local<Duration>,
 ) {
-    for folder in font_manager.fonts.values() {
-        for font in folder.fonts.values() {
-            if !asset_server.is_loaded(font.id()) {
-                return;
+    let elapsed = time.map_or(Duration::ZERO, |time| time.elapsed());
+    if loading.is_added() {
+        *started = elapsed;
+    }
+    let timed_out = config
+        .font_loading_timeout
+        .is_some_and(|timeout| elapsed.saturating_sub(*started) >= timeout);
+
+    let mut loaded = 0;
+    let mut total = 0;
+    let mut failed = vec![];
+    let mut pending = vec![];
+    for (family, folder) in font_manager.fonts.iter() {
+        for font in folder
+            .fonts
+            .values()
+            .chain(folder.styled_fallbacks.values())
+            .chain(folder.fallbacks.iter())
+        {
+            total += 1;
+            match asset_server.load_state(font.id()) {
+                LoadState::Loaded => loaded += 1,
+                LoadState::Failed(err) => {
+                    bevy::log::warn!(
+                        "Font {} of the {} family failed to load, using the other fonts of the family: {}",
+                        font.path().map_or_else(|| format!("{:?}", font.id()), ToString::to_string),
+                        family,
+                        err
+                    );
+                    loaded += 1;
+                    failed.push((family.clone(), font.id()));
+                }
+                _ => pending.push((family.clone(), font.clone())),
             }
         }
     }
+    if timed_out && !pending.is_empty() {
+        bevy::log::warn!(
+            "Fonts are still loading after {:?}, continuing without them: {}",
+            config.font_loading_timeout.unwrap_or_default(),
+            pending
+                .iter()
+                .map(|(family, font)| format!(
+                    "{} of the {} family",
+                    font.path()
+                        .map_or_else(|| format!("{:?}", font.id()), ToString::to_string),
+                    family
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        failed.extend(
+            pending
+                .iter()
+                .map(|(family, font)| (family.clone(), font.id())),
+        );
+        loaded = total;
+    }
+    for (family, id) in failed {
+        if let Some(folder) = font_manager.fonts.get_mut(&family) {
+            folder.remove(id);
+        }
+    }
+    if loaded < total {
+        progress_events.send(FontLoadingProgress { loaded, total });
+        return;
+    }
+    if !timed_out && glyph_warmup.is_some_and(|warmup| warmup.is_pending()) {
+        return;
+    }
     commands.remove_resource::<FontsLoading>();
+    // Translates the texts again with the loaded fonts
+    match loading.families.as_ref() {
+        Some(families) => {
+            for mut dyn_font in dyn_fonts.iter_mut() {
+                if families.contains(&dyn_font.family) {
+                    dyn_font.set_changed();
+                }
+            }
+        }
+        None => font_manager.set_changed(),
+    }
+    loaded_events.send(FontsLoaded);
     bevy::log::debug!("All fonts loaded");
 }
 
-/// Auto updates the translations for the text entities that have the [I18nText] component
-/// whenever the [I18n] resource changes
-fn update_translations(
-    font_manager: bevy::ecs::system::Res<FontManager>,
-    mut text_query: Query<
-        (&mut Text, &mut TextFont, Option<&I18nFont>, &I18nText),
-        Without<I18nNumber>,
-    >,
-    mut num_query: Query<
-        (&mut Text, &mut TextFont, Option<&I18nFont>, &I18nNumber),
-        Without<I18nText>,
-    >,
+/// Number of frames after which the glyph warmup texts are despawned even if they weren't rendered,
+/// e.g. when there's no UI camera
+#[cfg(feature = "fonts")]
+const GLYPH_WARMUP_FRAMES: u32 = 10;
+
+/// Renders the characters of the current locale with hidden text entities once its fonts are loaded,
+/// and despawns them once their glyphs are in the atlas
+#[cfg(feature = "fonts")]
+fn warm_glyph_atlas(
+    mut commands: Commands,
+    mut warmup: ResMut<GlyphWarmup>,
+    i18n: Res<I18n>,
+    font_manager: Res<FontManager>,
+    fonts: Res<Assets<Font>>,
+    layouts: Query<&TextLayoutInfo>,
 ) {
-    bevy::log::debug!("Updating translations");
-    for (mut text, mut text_font, dyn_font, key) in text_query.iter_mut() {
-        text.0 = key.translate();
-        if let Some(dyn_font) = dyn_font {
-            text_font.font = font_manager.get(&dyn_font.0, key.locale.clone());
+    if !warmup.texts.is_empty() {
+        warmup.frames += 1;
+        let rendered = warmup.texts.iter().all(|entity| {
+            layouts
+                .get(*entity)
+                .is_ok_and(|layout| !layout.glyphs.is_empty())
+        });
+        if !rendered && warmup.frames < GLYPH_WARMUP_FRAMES {
+            return;
+        }
+        if !rendered {
+            bevy::log::debug!("Glyph warmup texts were not rendered, is there a UI camera?");
+        }
+        for entity in std::mem::take(&mut warmup.texts) {
+            commands.entity(entity).despawn();
+        }
+        bevy::log::debug!("Glyph atlas warmed up for {}", warmup.locale);
+    }
+
+    let locale = i18n.current().to_string();
+    if warmup.warmed.contains(&locale) {
+        warmup.locale = locale;
+        return;
+    }
+    let mut targets: Vec<(Handle<Font>, f32)> = vec![];
+    for folder in font_manager.fonts.values() {
+        let scale = folder.scale(&locale);
+        let weights = folder
+            .fonts
+            .keys()
+            .filter(|(font_locale, _)| is_locale_of(font_locale, &locale))
+            .map(|(_, style)| *style);
+        for style in [
+            FontStyle::Regular,
+            FontStyle::Bold,
+            FontStyle::Italic,
+            FontStyle::BoldItalic,
+        ]
+        .into_iter()
+        .chain(weights)
+        {
+            let font = folder.get(locale.as_str(), style);
+            if !targets.iter().any(|(target, _)| *target == font) {
+                targets.push((font, scale));
+            }
+        }
+    }
+    // Waits for the fonts of the locale, the ones that fail to load are removed from their family
+    if targets
+        .iter()
+        .any(|(font, _)| *font != Handle::default() && !fonts.contains(font))
+    {
+        return;
+    }
+
+    let glyphs = i18n.glyphs(&locale);
+    bevy::log::debug!(
+        "Warming up the glyph atlas with {} characters for {}",
+        glyphs.chars().count(),
+        locale
+    );
+    let mut texts = vec![];
+    for (font, scale) in targets.into_iter().filter(|(font, _)| fonts.contains(font)) {
+        for font_size in warmup.font_sizes.iter() {
+            texts.push(
+                commands
+                    .spawn((
+                        Text::new(glyphs.clone()),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: font_size * scale,
+                            ..Default::default()
+                        },
+                        TextColor(Color::NONE),
+                        Node {
+                            position_type: PositionType::Absolute,
+                            ..Default::default()
+                        },
+                    ))
+                    .id(),
+            );
+        }
+    }
+    warmup.texts = texts;
+    warmup.frames = 0;
+    warmup.warmed.insert(locale.clone());
+    warmup.locale = locale;
+}
+
+/// Starts the [FontsLoading] phase again to warm up the glyph atlas for a locale that wasn't warmed up yet
+#[cfg(feature = "fonts")]
+fn restart_glyph_warmup(mut commands: Commands, warmup: Res<GlyphWarmup>, i18n: Res<I18n>) {
+    if !warmup.warmed.contains(i18n.current()) {
+        commands.init_resource::<FontsLoading>();
+    }
+}
+
+/// Sends a [LocaleChanged] event when the locale of the [I18n] resource differs from the last applied one,
+/// also triggered for the observers with the `observers` feature
+#[cfg_attr(not(feature = "observers"), allow(unused_mut, unused_variables))]
+fn emit_locale_changed(
+    mut commands: Commands,
+    i18n: Res<I18n>,
+    mut active_locale: ResMut<ActiveLocale>,
+    mut events: EventWriter<LocaleChanged>,
+) {
+    if active_locale.0 == i18n.current() {
+        return;
+    }
+    let previous = std::mem::replace(&mut active_locale.0, i18n.current().to_string());
+    bevy::log::debug!(
+        "Sending locale changed event: {} -> {}",
+        previous,
+        i18n.current()
+    );
+    let event = LocaleChanged {
+        previous,
+        current: i18n.current().to_string(),
+    };
+    #[cfg(feature = "observers")]
+    commands.trigger(event.clone());
+    events.send(event);
+}
+
+/// Updates the [LayoutDirection] to the direction of the new locale, sending a [DirectionChanged] event when it changes
+fn update_layout_direction(
+    mut locale_events: EventReader<LocaleChanged>,
+    mut direction: ResMut<LayoutDirection>,
+    mut events: EventWriter<DirectionChanged>,
+) {
+    let Some(event) = locale_events.read().last() else {
+        return;
+    };
+    set_layout_direction(
+        &mut direction,
+        &mut events,
+        TextDirection::of(&event.current),
+    );
+}
+
+/// Updates the [LayoutDirection] to the direction of the startup locale,
+/// which the platform, system and persisted locales set without a [LocaleChanged] event
+fn init_layout_direction(
+    i18n: Res<I18n>,
+    mut direction: ResMut<LayoutDirection>,
+    mut events: EventWriter<DirectionChanged>,
+) {
+    set_layout_direction(&mut direction, &mut events, i18n.direction());
+}
+
+fn set_layout_direction(
+    direction: &mut LayoutDirection,
+    events: &mut EventWriter<DirectionChanged>,
+    current: TextDirection,
+) {
+    if direction.0 == current {
+        return;
+    }
+    bevy::log::debug!("Layout direction changed to {:?}", current);
+    let previous = std::mem::replace(&mut direction.0, current);
+    events.send(DirectionChanged { previous, current });
+}
+
+/// Marks the nodes of the [MirrorLayout] subtrees that are currently mirrored
+#[derive(Component)]
+struct Mirrored;
+
+/// Mirrors the nodes of the [MirrorLayout] subtrees while the [LayoutDirection] is right-to-left, and flips them back otherwise,
+/// including the nodes that are added to the subtrees later
+///
+/// The [NoMirror] entities and their descendants are skipped
+#[allow(clippy::type_complexity)]
+fn mirror_layouts(
+    mut commands: Commands,
+    direction: Res<LayoutDirection>,
+    roots: Query<Entity, With<MirrorLayout>>,
+    added: Query<(), Or<(Added<MirrorLayout>, Changed<Children>)>>,
+    hierarchy: Query<(Option<&Parent>, Option<&Children>)>,
+    mut nodes: Query<(&mut Node, Has<Mirrored>), Without<NoMirror>>,
+) {
+    if !direction.is_changed() && added.is_empty() {
+        return;
+    }
+    let rtl = direction.is_rtl();
+    for root in roots.iter() {
+        // Whether the parent lays its children out in a row, its horizontal axis is then its main axis
+        let in_row = hierarchy
+            .get(root)
+            .ok()
+            .and_then(|(parent, _)| nodes.get(parent?.get()).ok())
+            .is_some_and(|(node, _)| is_row(node));
+        let mut stack = vec![(root, in_row)];
+        while let Some((entity, in_row)) = stack.pop() {
+            let Ok((mut node, mirrored)) = nodes.get_mut(entity) else {
+                continue;
+            };
+            if mirrored != rtl {
+                mirror_node(&mut node, in_row);
+                match rtl {
+                    true => commands.entity(entity).insert(Mirrored),
+                    false => commands.entity(entity).remove::<Mirrored>(),
+                };
+            }
+            let row = is_row(&node);
+            if let Ok((_, Some(children))) = hierarchy.get(entity) {
+                stack.extend(children.iter().map(|child| (*child, row)));
+            }
+        }
+    }
+}
+
+fn is_row(node: &Node) -> bool {
+    matches!(
+        node.flex_direction,
+        FlexDirection::Row | FlexDirection::RowReverse
+    )
+}
+
+/// Flips the layout of a node horizontally, `in_row` when its parent lays it out in a row
+///
+/// Mirroring twice restores the node
+fn mirror_node(node: &mut Node, in_row: bool) {
+    node.flex_direction = match node.flex_direction {
+        FlexDirection::Row => FlexDirection::RowReverse,
+        FlexDirection::RowReverse => FlexDirection::Row,
+        direction => direction,
+    };
+    // Reversing a row already mirrors the alignments relative to its direction
+    if is_row(node) {
+        node.justify_content = match node.justify_content {
+            JustifyContent::Start => JustifyContent::End,
+            JustifyContent::End => JustifyContent::Start,
+            justify => justify,
+        };
+    } else {
+        node.align_items = match node.align_items {
+            AlignItems::Start => AlignItems::End,
+            AlignItems::End => AlignItems::Start,
+            AlignItems::FlexStart => AlignItems::FlexEnd,
+            AlignItems::FlexEnd => AlignItems::FlexStart,
+            align => align,
+        };
+        node.align_content = match node.align_content {
+            AlignContent::Start => AlignContent::End,
+            AlignContent::End => AlignContent::Start,
+            AlignContent::FlexStart => AlignContent::FlexEnd,
+            AlignContent::FlexEnd => AlignContent::FlexStart,
+            align => align,
+        };
+    }
+    if !in_row {
+        node.align_self = match node.align_self {
+            AlignSelf::Start => AlignSelf::End,
+            AlignSelf::End => AlignSelf::Start,
+            AlignSelf::FlexStart => AlignSelf::FlexEnd,
+            AlignSelf::FlexEnd => AlignSelf::FlexStart,
+            align => align,
+        };
+    }
+    // Only used by the grids, which are always laid out horizontally
+    node.justify_items = match node.justify_items {
+        JustifyItems::Start => JustifyItems::End,
+        JustifyItems::End => JustifyItems::Start,
+        justify => justify,
+    };
+    node.justify_self = match node.justify_self {
+        JustifySelf::Start => JustifySelf::End,
+        JustifySelf::End => JustifySelf::Start,
+        justify => justify,
+    };
+    std::mem::swap(&mut node.left, &mut node.right);
+    for rect in [&mut node.margin, &mut node.padding, &mut node.border] {
+        std::mem::swap(&mut rect.left, &mut rect.right);
+    }
+}
+
+/// A run of translated text rendered with a font and an optional color
+type Span<'a> = (Cow<'a, str>, Handle<Font>, Option<Color>);
+
+/// Spans spawned as children of a text entity to render its markup,
+/// or the characters that its font lacks with the fallback font
+///
+/// Keeps what they were spawned with, so they're only respawned when the translation or style changes
+#[derive(Component)]
+pub(crate) struct TranslatedSpans {
+    entities: Vec<Entity>,
+    spans: Vec<Span<'static>>,
+    font_size: f32,
+    color: Color,
+}
+
+/// Font size of a text entity before the scale of its locale was applied, so the scales don't compound
+#[cfg(feature = "fonts")]
+#[derive(Component)]
+pub(crate) struct BaseFontSize(f32);
+
+/// Query data of a text entity that's updated with its translation
+#[cfg(feature = "fonts")]
+type TextData<T> = (
+    Entity,
+    &'static mut T,
+    &'static mut TextFont,
+    Option<&'static TextColor>,
+    Option<&'static I18nFont>,
+    Option<&'static TranslatedSpans>,
+    Option<&'static BaseFontSize>,
+    Option<&'static TextSpacing>,
+);
+
+/// Query data of a text entity that's updated with its translation
+#[cfg(not(feature = "fonts"))]
+type TextData<T> = (
+    Entity,
+    &'static mut T,
+    &'static mut TextFont,
+    Option<&'static TextColor>,
+    Option<&'static TranslatedSpans>,
+);
+
+/// Writes the translations and dynamic fonts of the text entities
+#[derive(SystemParam)]
+struct TextWriter<'w, 's> {
+    commands: Commands<'w, 's>,
+    #[cfg(feature = "fonts")]
+    font_manager: Res<'w, FontManager>,
+    #[cfg(feature = "fonts")]
+    fonts: Res<'w, Assets<Font>>,
+}
+
+impl<'w, 's> TextWriter<'w, 's> {
+    /// Writer of the commands of a [ParallelCommands] scope, sharing the fonts of the writer of the system
+    fn scoped(
+        commands: Commands<'w, 's>,
+        #[cfg(feature = "fonts")] font_manager: &Res<'w, FontManager>,
+        #[cfg(feature = "fonts")] fonts: &Res<'w, Assets<Font>>,
+    ) -> Self {
+        Self {
+            commands,
+            #[cfg(feature = "fonts")]
+            font_manager: Res::clone(font_manager),
+            #[cfg(feature = "fonts")]
+            fonts: Res::clone(fonts),
         }
     }
-    for (mut text, mut text_font, dyn_font, key) in num_query.iter_mut() {
-        text.0 = key.translate();
+
+    /// Writes the translation and the dynamic font of the locale,
+    /// the characters that the font can't render are split into child spans using the fallback font of the family
+    ///
+    /// With markup, the styled runs of the translation are also split into child spans,
+    /// bold and italic runs use the matching style of the [I18nFont] and are left unstyled without one
+    ///
+    /// The placeholder is written instead, with the fallback font, while the font of the locale is still loading
+    #[cfg_attr(not(feature = "fonts"), allow(unused_variables))]
+    fn write<T: Component + TextSpanAccess>(
+        &mut self,
+        data: QueryItem<TextData<T>>,
+        locale: Option<String>,
+        translated: Cow<'_, str>,
+        placeholder: Option<&str>,
+        markup: bool,
+    ) {
+        #[cfg(feature = "fonts")]
+        let (entity, text, text_font, text_color, dyn_font, spans, base_size, spacing) = data;
+        #[cfg(not(feature = "fonts"))]
+        let (entity, text, text_font, text_color, spans) = data;
+        let runs = markup.then(|| parse_markup(&translated)).flatten();
+        #[cfg(feature = "fonts")]
         if let Some(dyn_font) = dyn_font {
-            text_font.font = font_manager.get(&dyn_font.0, key.locale.clone());
+            self.write_font(
+                (
+                    entity, text, text_font, text_color, spans, base_size, spacing,
+                ),
+                dyn_font,
+                locale,
+                translated,
+                placeholder,
+                runs,
+            );
+            return;
+        }
+        match runs {
+            Some(runs) => {
+                let mut new_spans = vec![];
+                for (run, style) in runs {
+                    push_span(
+                        &mut new_spans,
+                        run.into(),
+                        text_font.font.clone(),
+                        style.color,
+                    );
+                }
+                self.spawn_spans(entity, text, &text_font, text_color, spans, new_spans);
+            }
+            None => self.write_text(entity, text, spans, &translated),
+        }
+    }
+
+    /// Writes the translation of an [I18nText], see [TextWriter::write]
+    fn write_key<T: Component + TextSpanAccess>(
+        &mut self,
+        data: QueryItem<TextData<T>>,
+        key: &I18nText,
+        i18n: &I18n,
+    ) {
+        self.write(
+            data,
+            key.locale.clone(),
+            key.translate(i18n),
+            key.loading_placeholder.as_deref(),
+            key.markup,
+        );
+    }
+
+    /// Writes the formatted number of an [I18nNumber], see [TextWriter::write]
+    fn write_number<T: Component + TextSpanAccess>(
+        &mut self,
+        data: QueryItem<TextData<T>>,
+        number: &I18nNumber,
+    ) {
+        self.write(
+            data,
+            number.locale.clone(),
+            number.translate().into(),
+            None,
+            false,
+        );
+    }
+
+    /// Writes the translation of an entity with an [I18nFont], see [TextWriter::write]
+    #[cfg(feature = "fonts")]
+    #[allow(clippy::type_complexity)]
+    fn write_font<T: Component + TextSpanAccess>(
+        &mut self,
+        (entity, text, mut text_font, text_color, spans, base_size, spacing): (
+            Entity,
+            Mut<T>,
+            Mut<TextFont>,
+            Option<&TextColor>,
+            Option<&TranslatedSpans>,
+            Option<&BaseFontSize>,
+            Option<&TextSpacing>,
+        ),
+        dyn_font: &I18nFont,
+        locale: Option<String>,
+        translated: Cow<'_, str>,
+        placeholder: Option<&str>,
+        runs: Option<Vec<(String, MarkupStyle)>>,
+    ) {
+        let font_locale = locale
+            .clone()
+            .unwrap_or_else(|| rust_i18n::locale().to_string());
+        let scale = self.font_manager.font_scale(&dyn_font.family, &font_locale);
+        let locale_spacing = self
+            .font_manager
+            .text_spacing(&dyn_font.family, &font_locale);
+        if spacing.copied().unwrap_or_default() != locale_spacing {
+            self.commands.entity(entity).insert(locale_spacing);
+        }
+        match base_size {
+            Some(base_size) if text_font.font_size != base_size.0 * scale => {
+                text_font.font_size = base_size.0 * scale;
+            }
+            Some(_) => {}
+            None if scale != 1.0 => {
+                self.commands
+                    .entity(entity)
+                    .insert(BaseFontSize(text_font.font_size));
+                text_font.font_size *= scale;
+            }
+            None => {}
+        }
+        let font = self
+            .font_manager
+            .get(&dyn_font.family, locale.clone(), dyn_font.style);
+
+        if let Some(placeholder) = placeholder {
+            if !self.fonts.contains(&font) {
+                bevy::log::debug!("Font is still loading, showing the placeholder");
+                let fallback = self
+                    .font_manager
+                    .glyph_fallbacks(&dyn_font.family, dyn_font.style)
+                    .into_iter()
+                    .next()
+                    .unwrap_or(font);
+                if text_font.font != fallback {
+                    text_font.font = fallback;
+                }
+                self.write_text(entity, text, spans, placeholder);
+                return;
+            }
+        }
+        if text_font.font != font {
+            text_font.font = font;
+        }
+
+        let runs: Vec<(Cow<str>, MarkupStyle)> = match runs {
+            Some(runs) => runs
+                .into_iter()
+                .map(|(run, style)| (run.into(), style))
+                .collect(),
+            None => vec![(translated, MarkupStyle::default())],
+        };
+        let mut new_spans = vec![];
+        for (run, style) in runs {
+            let font_style = style.font_style(dyn_font.style);
+            let primary = if font_style == dyn_font.style {
+                text_font.font.clone()
+            } else {
+                self.font_manager
+                    .get(&dyn_font.family, locale.clone(), font_style)
+            };
+            let mut fonts = vec![primary];
+            for font in self
+                .font_manager
+                .glyph_fallbacks(&dyn_font.family, font_style)
+            {
+                if !fonts.contains(&font) {
+                    fonts.push(font);
+                }
+            }
+            match split_glyph_runs(&run, &fonts, &self.fonts) {
+                Some(glyph_runs) => {
+                    bevy::log::debug!(
+                        "Splitting {} into {} spans for the glyph fallback",
+                        run,
+                        glyph_runs.len()
+                    );
+                    for (glyph_run, index) in glyph_runs {
+                        push_span(
+                            &mut new_spans,
+                            glyph_run.into(),
+                            fonts[index].clone(),
+                            style.color,
+                        );
+                    }
+                }
+                None => push_span(&mut new_spans, run, fonts.swap_remove(0), style.color),
+            }
+        }
+        match new_spans.as_slice() {
+            [(translated, font, None)] if *font == text_font.font => {
+                self.write_text(entity, text, spans, translated);
+            }
+            _ => self.spawn_spans(entity, text, &text_font, text_color, spans, new_spans),
+        }
+    }
+
+    /// Writes the text of the entity, despawning the spans of its previous translation
+    ///
+    /// The text is left untouched when it didn't change, so its layout isn't recomputed,
+    /// and its buffer is reused otherwise
+    fn write_text<T: Component + TextSpanAccess>(
+        &mut self,
+        entity: Entity,
+        mut text: Mut<T>,
+        previous: Option<&TranslatedSpans>,
+        translated: &str,
+    ) {
+        if let Some(previous) = previous {
+            self.despawn_spans(entity, previous);
+        }
+        if text.read_span() != translated {
+            let span = text.write_span();
+            span.clear();
+            span.push_str(translated);
+        }
+    }
+
+    fn despawn_spans(&mut self, entity: Entity, spans: &TranslatedSpans) {
+        for span in spans.entities.iter() {
+            self.commands.entity(*span).despawn_recursive();
+        }
+        self.commands.entity(entity).remove::<TranslatedSpans>();
+    }
+
+    /// Replaces the text of the entity with child spans of the provided text, font and color,
+    /// the spans without a color use the one of the entity
+    ///
+    /// The previous spans are kept when they're the same
+    fn spawn_spans<T: Component + TextSpanAccess>(
+        &mut self,
+        entity: Entity,
+        mut text: Mut<T>,
+        text_font: &TextFont,
+        text_color: Option<&TextColor>,
+        previous: Option<&TranslatedSpans>,
+        spans: Vec<Span<'_>>,
+    ) {
+        let default_color = text_color.copied().unwrap_or_default();
+        if let Some(previous) = previous {
+            if previous.spans == spans
+                && previous.font_size == text_font.font_size
+                && previous.color == default_color.0
+            {
+                return;
+            }
+            self.despawn_spans(entity, previous);
+        }
+        if !text.read_span().is_empty() {
+            text.write_span().clear();
+        }
+        let spans: Vec<Span<'static>> = spans
+            .into_iter()
+            .map(|(run, font, color)| (Cow::Owned(run.into_owned()), font, color))
+            .collect();
+        let children: Vec<Entity> = spans
+            .iter()
+            .cloned()
+            .map(|(run, font, color)| {
+                let font = TextFont {
+                    font,
+                    ..text_font.clone()
+                };
+                let color = color.map_or(default_color, TextColor);
+                self.commands.spawn((TextSpan::new(run), font, color)).id()
+            })
+            .collect();
+        // Inserted first so they take the place of the entity's own text, before any existing span
+        self.commands
+            .entity(entity)
+            .insert_children(0, &children)
+            .insert(TranslatedSpans {
+                entities: children,
+                spans,
+                font_size: text_font.font_size,
+                color: default_color.0,
+            });
+    }
+}
+
+/// Appends the text to the last span when it has the same font and color, e.g. bold runs of a family without a bold font
+fn push_span<'a>(
+    spans: &mut Vec<Span<'a>>,
+    text: Cow<'a, str>,
+    font: Handle<Font>,
+    color: Option<Color>,
+) {
+    match spans.last_mut() {
+        Some((run, last_font, last_color)) if *last_font == font && *last_color == color => {
+            run.to_mut().push_str(&text)
         }
+        _ => spans.push((text, font, color)),
     }
 }
+
+/// Splits a text into runs of characters that are rendered with the same font,
+/// each character uses the first font that has a glyph for it, or the primary font when none does
+///
+/// Whitespace stays in the current run
+///
+/// `None` when there's no fallback font, a font isn't loaded yet, or the primary font can render the whole text
+#[cfg(feature = "fonts")]
+fn split_glyph_runs(
+    text: &str,
+    fonts: &[Handle<Font>],
+    assets: &Assets<Font>,
+) -> Option<Vec<(String, usize)>> {
+    if fonts.len() < 2 {
+        return None;
+    }
+    let faces = fonts
+        .iter()
+        .map(|font| ttf_parser::Face::parse(&assets.get(font)?.data, 0).ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut runs: Vec<(String, usize)> = vec![];
+    for char in text.chars() {
+        let index = match runs.last() {
+            Some((_, current)) if char.is_whitespace() || char.is_control() => *current,
+            _ => faces
+                .iter()
+                .position(|face| face.glyph_index(char).is_some())
+                .unwrap_or(0),
+        };
+        match runs.last_mut() {
+            Some((run, current)) if *current == index => run.push(char),
+            _ => runs.push((char.to_string(), index)),
+        }
+    }
+    runs.iter().any(|(_, index)| *index != 0).then_some(runs)
+}
+
+/// Number of text entities from which [update_translations] translates them in parallel when they're all updated,
+/// the small worlds keep the simpler serial update, see `benches/translations.rs`
+const PARALLEL_TRANSLATIONS_THRESHOLD: usize = 2_000;
+
+/// Number of text entities from which they're translated in parallel, see [I18nPlugin::with_parallel_threshold]
+#[derive(bevy::ecs::system::Resource)]
+struct ParallelThreshold(usize);
+
+/// Auto updates the translations for the text entities that have the [I18nText] component
+/// whenever the [I18n] resource changes, the [FontManager] when a font scale is set, or a [RefreshTranslations] event is sent
+///
+/// Otherwise, only the text entities that were spawned or changed since the last run are translated,
+/// e.g. a new interpolation argument, or an [I18nFont] that was added or changed so the font of its family is applied
+///
+/// Generic over the text component so UI [Text], world-space [Text2d] and [TextSpan] children are covered
+///
+/// The texts pinned to a locale are skipped when only the current locale changed, unless they changed too,
+/// and the texts whose translation didn't change are left untouched so their layout isn't recomputed
+///
+/// Every text is translated in parallel when there are at least [ParallelThreshold] of them.
+/// The changed texts are translated serially, since they're usually a handful of entities per frame
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn update_translations<T: Component + TextSpanAccess>(
+    i18n: Res<I18n>,
+    mut writer: TextWriter,
+    par_commands: ParallelCommands,
+    parallel_threshold: Res<ParallelThreshold>,
+    mut refresh_events: EventReader<RefreshTranslations>,
+    #[cfg(feature = "observers")] mut locale_events: EventReader<LocaleChanged>,
+    #[cfg(feature = "observers")] mut observed: ResMut<ObservedTexts<T>>,
+    #[cfg(feature = "observers")] ticks: SystemChangeTick,
+    mut last_revision: Local<Option<u64>>,
+    mut text_queries: ParamSet<(
+        Query<(TextData<T>, &I18nText), Without<I18nNumber>>,
+        Query<
+            (TextData<T>, &I18nText, Triggers<I18nText>),
+            (Without<I18nNumber>, TextChanged<I18nText>),
+        >,
+    )>,
+    mut num_queries: ParamSet<(
+        Query<(TextData<T>, &I18nNumber), Without<I18nText>>,
+        Query<
+            (TextData<T>, &I18nNumber, Triggers<I18nNumber>),
+            (Without<I18nText>, TextChanged<I18nNumber>),
+        >,
+    )>,
+) {
+    #[cfg(feature = "fonts")]
+    let fonts_changed = writer.font_manager.is_changed();
+    #[cfg(not(feature = "fonts"))]
+    let fonts_changed = false;
+    let all = i18n.is_changed() || fonts_changed || !refresh_events.is_empty();
+    // When only the current locale changed, the texts pinned to another locale keep their translation
+    let locale_only = all
+        && *last_revision == Some(i18n.revision())
+        && !fonts_changed
+        && refresh_events.is_empty();
+    refresh_events.clear();
+    *last_revision = Some(i18n.revision());
+    // The observers already translated the texts of the new locale and the inserted ones
+    #[cfg(feature = "observers")]
+    let all_written = locale_events.read().count() > 0 && locale_only;
+    #[cfg(feature = "observers")]
+    let observed = std::mem::take(&mut observed.0);
+    #[cfg(not(feature = "observers"))]
+    let all_written = false;
+
+    let parallel = all
+        && !all_written
+        && text_queries.p0().iter().len() + num_queries.p0().iter().len() >= parallel_threshold.0;
+    if parallel {
+        bevy::log::debug!("Updating translations in parallel");
+        #[cfg(feature = "fonts")]
+        let (font_manager, fonts) = (&writer.font_manager, &writer.fonts);
+        text_queries.p0().par_iter_mut().for_each(|(data, key)| {
+            if locale_only && key.locale.is_some() {
+                return;
+            }
+            par_commands.command_scope(|commands| {
+                TextWriter::scoped(
+                    commands,
+                    #[cfg(feature = "fonts")]
+                    font_manager,
+                    #[cfg(feature = "fonts")]
+                    fonts,
+                )
+                .write_key(data, key, &i18n);
+            });
+        });
+        num_queries.p0().par_iter_mut().for_each(|(data, key)| {
+            if locale_only && key.locale.is_some() {
+                return;
+            }
+            par_commands.command_scope(|commands| {
+                TextWriter::scoped(
+                    commands,
+                    #[cfg(feature = "fonts")]
+                    font_manager,
+                    #[cfg(feature = "fonts")]
+                    fonts,
+                )
+                .write_number(data, key);
+            });
+        });
+    } else if all && !all_written {
+        bevy::log::debug!("Updating translations");
+        for (data, key) in text_queries.p0().iter_mut() {
+            if locale_only && key.locale.is_some() {
+                continue;
+            }
+            writer.write_key(data, key, &i18n);
+        }
+        for (data, key) in num_queries.p0().iter_mut() {
+            if locale_only && key.locale.is_some() {
+                continue;
+            }
+            writer.write_number(data, key);
+        }
+    }
+    if all && !locale_only {
+        return;
+    }
+    // The changed texts that weren't translated above, each entity is only written once per run
+    for (data, key, _triggers) in text_queries.p1().iter_mut() {
+        if all && key.locale.is_none() {
+            continue;
+        }
+        #[cfg(feature = "observers")]
+        if is_observed(&observed, data.0, &_triggers, ticks.this_run()) {
+            continue;
+        }
+        writer.write_key(data, key, &i18n);
+    }
+    for (data, key, _triggers) in num_queries.p1().iter_mut() {
+        if all && key.locale.is_none() {
+            continue;
+        }
+        #[cfg(feature = "observers")]
+        if is_observed(&observed, data.0, &_triggers, ticks.this_run()) {
+            continue;
+        }
+        writer.write_number(data, key);
+    }
+}
+
+/// Registers the observers translating the text entities of type `T`
+#[cfg(feature = "observers")]
+fn add_translation_observers<T: Component + TextSpanAccess>(app: &mut bevy::prelude::App) {
+    app.init_resource::<ObservedTexts<T>>()
+        .add_observer(translate_inserted::<I18nText, T>)
+        .add_observer(translate_inserted::<I18nNumber, T>)
+        .add_observer(translate_inserted::<T, T>)
+        .add_observer(translate_on_locale_changed::<T>);
+    #[cfg(feature = "fonts")]
+    app.add_observer(translate_inserted::<I18nFont, T>);
+}
+
+/// Text entities of type `T` translated by the observers since the last [update_translations], with the tick they were translated at
+#[cfg(feature = "observers")]
+#[derive(bevy::ecs::system::Resource)]
+struct ObservedTexts<T>(EntityHashMap<Tick>, std::marker::PhantomData<fn() -> T>);
+
+#[cfg(feature = "observers")]
+impl<T> Default for ObservedTexts<T> {
+    fn default() -> Self {
+        Self(Default::default(), std::marker::PhantomData)
+    }
+}
+
+/// Change detection of the components that translate a text entity again when they change
+#[cfg(all(feature = "observers", feature = "fonts"))]
+type Triggers<C> = (Ref<'static, C>, Option<Ref<'static, I18nFont>>);
+
+/// Change detection of the components that translate a text entity again when they change
+#[cfg(all(feature = "observers", not(feature = "fonts")))]
+type Triggers<C> = (Ref<'static, C>,);
+
+#[cfg(not(feature = "observers"))]
+type Triggers<C> = std::marker::PhantomData<C>;
+
+/// Whether the observers translated the entity after its components last changed
+#[cfg(feature = "observers")]
+fn is_observed<C: Component>(
+    observed: &EntityHashMap<Tick>,
+    entity: Entity,
+    triggers: &QueryItem<Triggers<C>>,
+    this_run: Tick,
+) -> bool {
+    let Some(tick) = observed.get(&entity) else {
+        return false;
+    };
+    #[cfg(feature = "fonts")]
+    if triggers
+        .1
+        .as_ref()
+        .is_some_and(|font| font.last_changed().is_newer_than(*tick, this_run))
+    {
+        return false;
+    }
+    !triggers.0.last_changed().is_newer_than(*tick, this_run)
+}
+
+/// Translates a text entity as soon as its component `C` is inserted, including its text component `T`
+/// when it's inserted after the [I18nText], so it's rendered with its translation and font from its first frame
+///
+/// The entities are only translated once when several of their components are inserted together
+#[cfg(feature = "observers")]
+#[allow(clippy::type_complexity)]
+fn translate_inserted<C: Component, T: Component + TextSpanAccess>(
+    trigger: Trigger<OnInsert, C>,
+    i18n: Res<I18n>,
+    mut writer: TextWriter,
+    mut observed: ResMut<ObservedTexts<T>>,
+    ticks: SystemChangeTick,
+    mut texts: Query<(TextData<T>, &I18nText, Triggers<I18nText>), Without<I18nNumber>>,
+    mut numbers: Query<(TextData<T>, &I18nNumber, Triggers<I18nNumber>), Without<I18nText>>,
+) {
+    let entity = trigger.entity();
+    if let Ok((data, key, triggers)) = texts.get_mut(entity) {
+        if is_observed(&observed.0, entity, &triggers, ticks.this_run()) {
+            return;
+        }
+        writer.write_key(data, key, &i18n);
+    } else if let Ok((data, key, triggers)) = numbers.get_mut(entity) {
+        if is_observed(&observed.0, entity, &triggers, ticks.this_run()) {
+            return;
+        }
+        writer.write_number(data, key);
+    } else {
+        return;
+    }
+    observed.0.insert(entity, ticks.this_run());
+}
+
+/// Translates the text entities that aren't pinned to a locale when the [LocaleChanged] event is triggered
+#[cfg(feature = "observers")]
+fn translate_on_locale_changed<T: Component + TextSpanAccess>(
+    _trigger: Trigger<LocaleChanged>,
+    i18n: Res<I18n>,
+    mut writer: TextWriter,
+    mut texts: Query<(TextData<T>, &I18nText), Without<I18nNumber>>,
+    mut numbers: Query<(TextData<T>, &I18nNumber), Without<I18nText>>,
+) {
+    bevy::log::debug!("Updating translations of the new locale");
+    for (data, key) in texts.iter_mut().filter(|(_, key)| key.locale.is_none()) {
+        writer.write_key(data, key, &i18n);
+    }
+    for (data, key) in numbers.iter_mut().filter(|(_, key)| key.locale.is_none()) {
+        writer.write_number(data, key);
+    }
+}
+
+/// Query data of an input widget whose placeholder is localized
+#[cfg(feature = "fonts")]
+type PlaceholderData<W> = (
+    Ref<'static, I18nPlaceholder>,
+    &'static mut W,
+    Option<&'static mut TextFont>,
+    Option<Ref<'static, I18nFont>>,
+);
+
+/// Query data of an input widget whose placeholder is localized
+#[cfg(not(feature = "fonts"))]
+type PlaceholderData<W> = (Ref<'static, I18nPlaceholder>, &'static mut W);
+
+/// Writes the translation of the [I18nPlaceholder] of the input widgets `W`, along with the dynamic font of the locale,
+/// for the widgets that were spawned or changed since the last run, or for every one of them when the [I18n] resource,
+/// the fonts or the [RefreshTranslations] event changed them
+fn update_placeholders<W: TextPlaceholder>(
+    i18n: Res<I18n>,
+    #[cfg(feature = "fonts")] font_manager: Res<FontManager>,
+    mut refresh_events: EventReader<RefreshTranslations>,
+    mut query: Query<PlaceholderData<W>>,
+) {
+    #[cfg(feature = "fonts")]
+    let fonts_changed = font_manager.is_changed();
+    #[cfg(not(feature = "fonts"))]
+    let fonts_changed = false;
+    let all = i18n.is_changed() || fonts_changed || !refresh_events.is_empty();
+    refresh_events.clear();
+
+    for data in query.iter_mut() {
+        #[cfg(feature = "fonts")]
+        let (placeholder, mut widget, text_font, dyn_font) = data;
+        #[cfg(not(feature = "fonts"))]
+        let (placeholder, mut widget) = data;
+        #[cfg(feature = "fonts")]
+        let font_changed = dyn_font.as_ref().is_some_and(DetectChanges::is_changed);
+        #[cfg(not(feature = "fonts"))]
+        let font_changed = false;
+        if !all && !font_changed && !placeholder.is_changed() && !widget.is_added() {
+            continue;
+        }
+
+        let translated = placeholder.translate(&i18n);
+        let translated = match placeholder.markup {
+            true => Cow::Owned(strip_markup(&translated)),
+            false => translated,
+        };
+        if widget.placeholder() != translated {
+            widget.set_placeholder(translated.into_owned());
+        }
+        #[cfg(feature = "fonts")]
+        if let (Some(mut text_font), Some(dyn_font)) = (text_font, dyn_font) {
+            let font =
+                font_manager.get(&dyn_font.family, placeholder.locale.clone(), dyn_font.style);
+            if text_font.font != font {
+                text_font.font = font;
+            }
+        }
+    }
+}
+
+/// Writes the translation of the [I18nString] of the components `T` for the entities that were spawned or changed
+/// since the last run, or for every one of them when the [I18n] resource or the [RefreshTranslations] event changed them
+fn update_string_targets<T: StringTarget>(
+    i18n: Res<I18n>,
+    mut refresh_events: EventReader<RefreshTranslations>,
+    mut query: Query<(Ref<I18nString>, &mut T)>,
+) {
+    let all = i18n.is_changed() || !refresh_events.is_empty();
+    refresh_events.clear();
+
+    for (string, mut target) in query.iter_mut() {
+        if !all && !string.is_changed() && !target.is_added() {
+            continue;
+        }
+        let translated = string.translate(&i18n);
+        let translated = match string.markup {
+            true => Cow::Owned(strip_markup(&translated)),
+            false => translated,
+        };
+        if target.text() != translated {
+            target.set_text(translated.into_owned());
+        }
+    }
+}
+
+/// Writes the translation of the [I18nText] entities with an accessibility node to the node, along with the language of its locale,
+/// so screen readers announce it with the right voice
+///
+/// Bevy's labels hold their text in the value of the node, the other roles in its label
+#[cfg(feature = "accessibility")]
+fn update_accessibility_nodes(
+    i18n: Res<I18n>,
+    mut refresh_events: EventReader<RefreshTranslations>,
+    mut query: Query<(Ref<I18nText>, &mut bevy::a11y::AccessibilityNode)>,
+) {
+    let all = i18n.is_changed() || !refresh_events.is_empty();
+    refresh_events.clear();
+
+    for (text, mut node) in query.iter_mut() {
+        if !all && !text.is_changed() && !node.is_added() {
+            continue;
+        }
+        let translated = text.translate(&i18n);
+        let translated = match text.markup {
+            true => strip_markup(&translated),
+            false => translated.into_owned(),
+        };
+        let language = text
+            .locale
+            .as_deref()
+            .unwrap_or(i18n.current())
+            .replace('_', "-");
+        let label_in_value = node.role() == accesskit::Role::Label;
+        let current = match label_in_value {
+            true => node.value(),
+            false => node.label(),
+        };
+        if current == Some(translated.as_str()) && node.language() == Some(language.as_str()) {
+            continue;
+        }
+        match label_in_value {
+            true => node.set_value(translated),
+            false => node.set_label(translated),
+        }
+        node.set_language(language);
+    }
+}
+
+/// Loads the localized variant of the [I18nImage] entities that were spawned or changed since the last run,
+/// or of every one of them when the [I18n] resource changed
+#[allow(clippy::type_complexity)]
+fn update_images(
+    mut commands: Commands,
+    i18n: Res<I18n>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<(
+        Entity,
+        Ref<I18nImage>,
+        Option<&mut Sprite>,
+        Option<&mut ImageNode>,
+    )>,
+) {
+    for (entity, image, sprite, image_node) in query.iter_mut() {
+        if !i18n.is_changed() && !image.is_changed() {
+            continue;
+        }
+        let locale = image.locale.as_deref().unwrap_or(i18n.current());
+        let handle = asset_server.load(i18n.localized_path_in(locale, &image.path));
+        match (sprite, image_node) {
+            (Some(mut sprite), _) if sprite.image != handle => sprite.image = handle,
+            (_, Some(mut image_node)) if image_node.image != handle => image_node.image = handle,
+            (None, None) => {
+                commands.entity(entity).insert(ImageNode::new(handle));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Loads the clip of the [I18nAudio] entities that were spawned or changed since the last run,
+/// or of every one of them when the [I18n] resource changed
+///
+/// The entities that are already playing are skipped so the locale changing doesn't restart them
+#[cfg(feature = "audio")]
+#[allow(clippy::type_complexity)]
+fn update_audio(
+    mut commands: Commands,
+    i18n: Res<I18n>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<
+        (
+            Entity,
+            Ref<crate::components::I18nAudio>,
+            Option<&mut bevy::audio::AudioPlayer>,
+        ),
+        (
+            Without<bevy::audio::AudioSink>,
+            Without<bevy::audio::SpatialAudioSink>,
+        ),
+    >,
+) {
+    for (entity, audio, player) in query.iter_mut() {
+        if !i18n.is_changed() && !audio.is_changed() {
+            continue;
+        }
+        let locale = audio.locale.as_deref().unwrap_or(i18n.current());
+        let Some(path) = i18n.audio_path_in(locale, &audio.sound) else {
+            bevy::log::warn!("Sound {} has no clip for {}", audio.sound, locale);
+            continue;
+        };
+        let handle = asset_server.load(path);
+        match player {
+            Some(mut player) if player.0 != handle => player.0 = handle,
+            Some(_) => {}
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(bevy::audio::AudioPlayer::new(handle));
+            }
+        }
+    }
+}
+
+/// Filter of the text entities whose component or [I18nFont] changed
+#[cfg(feature = "fonts")]
+type TextChanged<C> = Or<(Changed<C>, Changed<I18nFont>)>;
+
+/// Filter of the text entities whose component changed
+#[cfg(not(feature = "fonts"))]
+type TextChanged<C> = Changed<C>;
@@ -0,0 +1,105 @@
+//! Pseudolocalization of the translations, enabled by the `pseudolocale` feature to test layouts before the real translations arrive
+
+use crate::resources::Delimiters;
+
+/// Locale that renders the translations of [crate::prelude::I18n::pseudo_source] pseudolocalized,
+/// `Settings` -> `[Šéţţíñĝš ~~~~]`, selectable like any other locale
+pub const PSEUDO_LOCALE: &str = "xa";
+
+/// Share of the length of a translation that's appended as padding, to simulate the expansion of longer languages
+const EXPANSION: f32 = 0.4;
+
+/// Accents the letters of a translation, pads it by 40% and wraps it in brackets,
+/// the placeholders and markup tags are kept as-is so they're still interpolated and styled
+pub(crate) fn pseudolocalize(text: &str, delimiters: &Delimiters) -> String {
+    let mut output = String::with_capacity(text.len() * 2 + 2);
+    let mut letters = 0;
+    let mut rest = text;
+    output.push('[');
+    while let Some(c) = rest.chars().next() {
+        let skipped = if let Some(placeholder) = rest.strip_prefix(delimiters.open()) {
+            placeholder
+                .find(delimiters.close())
+                .map(|end| delimiters.open().len() + end + delimiters.close().len())
+        } else if c == '<' {
+            rest.find('>').map(|end| end + 1)
+        } else {
+            None
+        };
+        if let Some(end) = skipped {
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+        if !c.is_whitespace() {
+            letters += 1;
+        }
+        output.push(accent(c));
+        rest = &rest[c.len_utf8()..];
+    }
+    let padding = (letters as f32 * EXPANSION).ceil() as usize;
+    if padding > 0 {
+        output.push(' ');
+        output.extend(std::iter::repeat_n('~', padding));
+    }
+    output.push(']');
+    output
+}
+
+fn accent(c: char) -> char {
+    match c {
+        'a' => 'á',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'ð',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'í',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ɱ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Ð',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Î',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ŕ',
+        'S' => 'Š',
+        'T' => 'Ţ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        c => c,
+    }
+}
@@ -0,0 +1,79 @@
+//! References of the translations to other keys, `Welcome to ${app.name}` embeds the translation of `app.name`
+//!
+//! References are resolved at lookup time in the locale of the translation, through its fallback chain,
+//! and can be nested in the referenced translations. They're resolved before the select expressions and
+//! the interpolation, so the referenced translations can use the arguments of the text
+
+use std::borrow::Cow;
+
+/// Depth of nested references after which they're left as-is
+const MAX_DEPTH: usize = 8;
+
+/// Replaces the `${key}` references of the translation of a key with the translations of their keys found by `lookup`
+///
+/// References to missing keys are left as-is, along with the ones that are part of a cycle or nested
+/// deeper than [MAX_DEPTH], which are logged as errors.
+/// The translation is returned as-is, without allocating, when it has no reference
+pub(crate) fn resolve_references<'a, 'b>(
+    key: &str,
+    text: Cow<'a, str>,
+    lookup: &impl Fn(&str) -> Option<Cow<'b, str>>,
+) -> Cow<'a, str> {
+    if !text.contains("${") {
+        return text;
+    }
+    let mut output = String::with_capacity(text.len());
+    resolve(&text, lookup, &mut vec![key.to_string()], &mut output);
+    Cow::Owned(output)
+}
+
+/// Writes the text with its references resolved to the output, `keys` are the keys being resolved, starting with the translated one
+fn resolve<'b>(
+    text: &str,
+    lookup: &impl Fn(&str) -> Option<Cow<'b, str>>,
+    keys: &mut Vec<String>,
+    output: &mut String,
+) {
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find('}') else {
+            break;
+        };
+        let reference = &rest[..=end];
+        let key = rest[2..end].trim();
+        rest = &rest[end + 1..];
+
+        if keys.iter().any(|k| k == key) {
+            bevy::log::error!(
+                "Translation reference {} references itself through {:?}, leaving it as-is",
+                reference,
+                keys
+            );
+            output.push_str(reference);
+            continue;
+        }
+        if keys.len() > MAX_DEPTH {
+            bevy::log::error!(
+                "Translation reference {} is nested deeper than {} references, leaving it as-is",
+                reference,
+                MAX_DEPTH
+            );
+            output.push_str(reference);
+            continue;
+        }
+        match lookup(key) {
+            Some(value) => {
+                keys.push(key.to_string());
+                resolve(&value, lookup, keys, output);
+                keys.pop();
+            }
+            None => {
+                bevy::log::debug!("Referenced translation key {} is missing", key);
+                output.push_str(reference);
+            }
+        }
+    }
+    output.push_str(rest);
+}
@@ -0,0 +1,59 @@
+//! Resolved translations of the locales, built on their first lookup so a key only takes a single hash lookup,
+//! including the keys that resolve to a fallback locale
+
+use std::{borrow::Cow, sync::OnceLock};
+
+use bevy::utils::hashbrown::HashMap;
+
+include!(concat!(env!("OUT_DIR"), "/bevy_simple_i18n_locale_keys.rs"));
+
+/// Final translations of the keys of a locale after its less specific variants and fallback locales,
+/// with the runtime translations taking precedence
+///
+/// Each translation is stored with the index of the locale it resolved to in the chain, `0` for the locale itself
+pub(crate) type ResolvedTable = HashMap<String, (usize, Cow<'static, str>)>;
+
+/// Resolved translations of the available locales, replaced whenever the translations change
+#[derive(Debug, Default)]
+pub(crate) struct ResolvedCache(Vec<(String, OnceLock<ResolvedTable>)>);
+
+impl ResolvedCache {
+    pub(crate) fn new(locales: &[String]) -> Self {
+        Self(
+            locales
+                .iter()
+                .map(|locale| (locale.clone(), OnceLock::new()))
+                .collect(),
+        )
+    }
+
+    /// Resolved translations of an available locale, built on the first call,
+    /// `None` for the other locales, e.g. a region variant without translations
+    pub(crate) fn get(
+        &self,
+        locale: &str,
+        build: impl FnOnce() -> ResolvedTable,
+    ) -> Option<&ResolvedTable> {
+        self.0
+            .iter()
+            .find(|(available, _)| available == locale)
+            .map(|(_, table)| {
+                table.get_or_init(|| {
+                    bevy::log::debug!("Resolving the translations of {}", locale);
+                    build()
+                })
+            })
+    }
+}
+
+/// Locales of the locale files, including the ones that are only loaded at runtime with the `external_translations` feature
+pub(crate) fn locales() -> impl Iterator<Item = &'static str> {
+    LOCALE_KEYS.iter().map(|(locale, _)| *locale)
+}
+
+/// Keys of the embedded translations of a locale
+pub(crate) fn embedded_keys(locale: &str) -> &'static [&'static str] {
+    LOCALE_KEYS
+        .binary_search_by_key(&locale, |(l, _)| l)
+        .map_or(&[], |index| LOCALE_KEYS[index].1)
+}
@@ -1,12 +1,183 @@
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+};
+
+#[cfg(feature = "fonts")]
 use bevy::{
-    asset::Handle,
-    ecs::{reflect::ReflectResource, system::Resource},
-    reflect::Reflect,
+    asset::{AssetId, Handle},
     text::Font,
-    utils::hashbrown::HashMap,
+};
+use bevy::{
+    ecs::{
+        reflect::ReflectResource,
+        system::Resource,
+        world::{FromWorld, World},
+    },
+    reflect::Reflect,
+    utils::hashbrown::{HashMap, HashSet},
 };
 use icu_locid::Locale;
 
+use crate::arg::{split_args, ToArg};
+use crate::resolved::{ResolvedCache, ResolvedTable};
+use crate::ruby::RubySegment;
+
+#[cfg(feature = "datetime")]
+use crate::formatters::DateTimeStyle;
+#[cfg(feature = "list_format")]
+use crate::formatters::ListStyle;
+#[cfg(feature = "numbers")]
+use crate::formatters::{MeasurementSystem, Unit};
+
+/// What [I18n] returns for a translation key that's missing in the requested locale and all of its fallbacks
+#[derive(Debug, Clone, Default, PartialEq, Eq, Reflect)]
+pub enum MissingKeyBehavior {
+    /// Return the translation key itself
+    #[default]
+    ReturnKey,
+    /// Return an empty string
+    Empty,
+    /// Panic with the missing key and locale, useful to catch typos in debug builds
+    Panic,
+    /// Return the provided placeholder, e.g. `"⟦missing⟧"`
+    Placeholder(String),
+}
+
+/// Delimiters of the interpolation placeholders of the translations, `{name}` by default
+///
+/// A `%` right before a placeholder is replaced along with it, so `%{name}` also works with the default delimiters.
+/// A backslash before the opening delimiter keeps it as literal text, `\{name}` -> `{name}`
+#[derive(Debug, Clone, PartialEq, Eq, Reflect)]
+pub struct Delimiters {
+    open: String,
+    close: String,
+}
+
+impl Delimiters {
+    /// Delimiters surrounding the name of the placeholders, e.g. `Delimiters::new("{{", "}}")` for `{{name}}`
+    ///
+    /// # Panics
+    ///
+    /// When one of the delimiters is empty
+    pub fn new(open: impl Into<String>, close: impl Into<String>) -> Self {
+        let (open, close) = (open.into(), close.into());
+        assert!(
+            !open.is_empty() && !close.is_empty(),
+            "Interpolation delimiters can't be empty"
+        );
+        Self { open, close }
+    }
+
+    /// Delimiter that opens a placeholder
+    pub fn open(&self) -> &str {
+        &self.open
+    }
+
+    /// Delimiter that closes a placeholder
+    pub fn close(&self) -> &str {
+        &self.close
+    }
+}
+
+impl Default for Delimiters {
+    fn default() -> Self {
+        Self::new("{", "}")
+    }
+}
+
+/// Where a raw translation was found, the runtime translations are copied into the [ResolvedCache]
+enum Source<'a> {
+    Runtime(&'a str),
+    Embedded(&'static str),
+}
+
+/// Error returned by [I18n::set_locale] when the locale isn't one of the available locales
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedLocaleError {
+    /// The requested locale
+    pub locale: String,
+    /// The locales that are available
+    pub available: Vec<String>,
+}
+
+impl std::fmt::Display for UnsupportedLocaleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unsupported locale: {}, available locales are {}",
+            self.locale,
+            self.available.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedLocaleError {}
+
+/// Base direction of the text of a locale
+///
+/// Useful for mirroring the UI layout, e.g. `FlexDirection::RowReverse` for [TextDirection::Rtl] locales
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum TextDirection {
+    /// Left-to-right, e.g. `en`, `fr`, `ja`
+    #[default]
+    Ltr,
+    /// Right-to-left, e.g. `ar`, `he`, `fa`
+    Rtl,
+}
+
+impl TextDirection {
+    /// Scripts that are written right-to-left
+    const RTL_SCRIPTS: [&'static str; 7] = ["Adlm", "Arab", "Hebr", "Nkoo", "Rohg", "Syrc", "Thaa"];
+    /// Languages that are written right-to-left when no script is specified
+    const RTL_LANGUAGES: [&'static str; 15] = [
+        "ar", "arc", "ckb", "dv", "fa", "he", "iw", "ks", "nqo", "ps", "sd", "syr", "ug", "ur",
+        "yi",
+    ];
+
+    /// Resolves the base direction of a locale from its script, see [LocaleInfo::script],
+    /// or its language when the script is unknown
+    ///
+    /// `ar` -> [TextDirection::Rtl], `pa-Arab` -> [TextDirection::Rtl], `en-US` -> [TextDirection::Ltr]
+    pub fn of(locale: &str) -> Self {
+        let Ok(parsed) = locale.replace('_', "-").parse::<Locale>() else {
+            return Self::Ltr;
+        };
+        let rtl = match crate::metadata::script(locale) {
+            Some(script) => Self::RTL_SCRIPTS.contains(&script.as_str()),
+            None => Self::RTL_LANGUAGES.contains(&parsed.id.language.as_str()),
+        };
+        if rtl {
+            Self::Rtl
+        } else {
+            Self::Ltr
+        }
+    }
+
+    pub fn is_rtl(&self) -> bool {
+        *self == Self::Rtl
+    }
+}
+
+/// Metadata of a locale for display in a language selection menu and for the layout, see [I18n::locale_info]
+#[derive(Debug, Clone, PartialEq, Eq, Reflect)]
+pub struct LocaleInfo {
+    /// Code of the locale, `pt-BR`
+    pub code: String,
+    /// Native name of the locale, see [I18n::native_name]
+    pub native_name: Option<String>,
+    /// Base direction of the text of the locale, see [TextDirection::of]
+    pub direction: TextDirection,
+    /// ISO 15924 code of the script of the locale, `Latn`, `Cyrl` or `Jpan`,
+    /// either its script subtag or the most likely one of its language, `None` when it's unknown
+    pub script: Option<String>,
+}
+
 /// Resource for managing the current locale and getting the available locales
 ///
 /// # Example
@@ -15,73 +186,1410 @@ use icu_locid::Locale;
 /// use bevy_simple_i18n::prelude::*;
 ///
 /// fn update_locale(mut i18n_res: ResMut<I18n>) {
-///     i18n_res.set_locale("en");
+///     if let Err(err) = i18n_res.set_locale("en") {
+///         error!("{}", err);
+///     }
+/// }
+///
+/// fn set_fallbacks(mut i18n_res: ResMut<I18n>) {
+///     // `pt-BR` -> `pt` -> `en`
+///     i18n_res.set_fallbacks(["pt", "en"]);
+/// }
+///
+/// fn log_greeting(i18n_res: Res<I18n>) {
+///     info!("{}", i18n_res.t_with("greet", &[("name", "Bevy User")]));
+/// }
+///
+/// fn format_score(i18n_res: Res<I18n>) {
+///     // `1,234,567` in `en`
+///     let score = i18n_res.format_number(1234567);
 /// }
+/// ```
 #[derive(Debug, Resource, Reflect)]
 #[reflect(Resource)]
 pub struct I18n {
     locales: Vec<String>,
     current: String,
+    /// Ordered locales that are tried when a key is missing in the requested locale
+    fallbacks: Vec<String>,
+    /// What to return once a key is missing in the requested locale and all of its fallbacks
+    missing_key_behavior: MissingKeyBehavior,
+    /// Delimiters of the interpolation placeholders, `{name}` by default
+    delimiters: Delimiters,
+    /// Number of times a missing key was hit this session
+    missing_keys: AtomicUsize,
+    /// Measurement system that overrides the one of the current locale, see [I18n::format_unit]
+    #[cfg(feature = "numbers")]
+    measurement_system: Option<MeasurementSystem>,
+    /// Translations loaded at runtime, they take precedence over the embedded ones
+    runtime: HashMap<String, HashMap<String, String>>,
+    /// Translations set with [I18n::set_override], they take precedence over every other translation
+    overrides: HashMap<String, HashMap<String, String>>,
+    /// Whether the texts display their keys instead of their translations, see [I18n::set_show_keys]
+    show_keys: bool,
+    /// Resolved translations of the locales, replaced when the translations change
+    #[reflect(ignore)]
+    resolved: ResolvedCache,
+    /// Incremented whenever something other than the current locale changes the translations,
+    /// so the texts pinned to a locale are only translated again when it changes
+    revision: u64,
+    /// `(locale, key)` pairs that were already warned about, so each missing key is only logged once
+    #[reflect(ignore)]
+    warned_missing_keys: Mutex<HashSet<(String, String)>>,
+    /// Missing keys hit since they were last moved to the [MissingKeyLog]
+    #[cfg(feature = "missing_key_log")]
+    #[reflect(ignore)]
+    pending_missing_keys: Mutex<Vec<MissingKey>>,
 }
 
 impl I18n {
-    pub fn set_locale(&mut self, locale: impl Into<String>) {
+    /// Set the current locale, which must be one of the available locales or a region variant of one, e.g. `de-AT` when `de` is available
+    ///
+    /// The current locale is left untouched when the locale isn't supported
+    pub fn set_locale(&mut self, locale: impl Into<String>) -> Result<(), UnsupportedLocaleError> {
         let next_locale: String = locale.into();
-        if let Err(err) = next_locale.parse::<Locale>() {
-            bevy::log::error!("Invalid locale: {}", err);
-            return;
+        if next_locale.parse::<Locale>().is_err() || self.find_locale(&next_locale).is_none() {
+            return Err(UnsupportedLocaleError {
+                locale: next_locale,
+                available: self.locales.clone(),
+            });
         }
         rust_i18n::set_locale(&next_locale);
         bevy::log::debug!("Locale changed from {} to {}", self.current, next_locale);
         self.current = next_locale;
+        Ok(())
     }
 
     pub fn current(&self) -> &str {
         &self.current
     }
 
+    /// Snapshot of the translations in the current locale that can be moved into async tasks, see [I18nHandle]
+    pub fn handle(&self) -> I18nHandle {
+        self.handle_in(&self.current)
+    }
+
+    /// Snapshot of the translations in the provided locale that can be moved into async tasks, see [I18nHandle]
+    pub fn handle_in(&self, locale: &str) -> I18nHandle {
+        I18nHandle(Arc::new(Self {
+            locales: self.locales.clone(),
+            current: locale.to_string(),
+            fallbacks: self.fallbacks.clone(),
+            missing_key_behavior: self.missing_key_behavior.clone(),
+            delimiters: self.delimiters.clone(),
+            missing_keys: AtomicUsize::new(0),
+            #[cfg(feature = "numbers")]
+            measurement_system: self.measurement_system,
+            runtime: self.runtime.clone(),
+            overrides: self.overrides.clone(),
+            show_keys: self.show_keys,
+            resolved: ResolvedCache::new(&self.locales),
+            revision: self.revision,
+            warned_missing_keys: Default::default(),
+            #[cfg(feature = "missing_key_log")]
+            pending_missing_keys: Default::default(),
+        }))
+    }
+
+    /// Same as [I18n::available_locales]
     pub fn locales(&self) -> &[String] {
         &self.locales
     }
+
+    /// Locales that were compiled in (and loaded at runtime), sorted alphabetically so the order is stable across builds
+    pub fn available_locales(&self) -> &[String] {
+        &self.locales
+    }
+
+    /// Native name of a locale for display in a language selection menu, `fr` -> `Français`, `ja` -> `日本語`
+    ///
+    /// Can be overridden per locale with a `_native_name` translation key,
+    /// `None` when the locale is neither in the built-in table nor overridden
+    pub fn native_name(&self, locale: &str) -> Option<Cow<'_, str>> {
+        #[cfg(feature = "pseudolocale")]
+        if locale == crate::pseudo::PSEUDO_LOCALE {
+            return Some(Cow::Borrowed("[Þšéûðöļöçáļé]"));
+        }
+        self.lookup_locale(locale, "_native_name")
+            .or_else(|| crate::metadata::native_name(locale).map(Cow::Borrowed))
+    }
+
+    /// Metadata of a locale, which doesn't need to be the current or even an available one,
+    /// `de` -> `Deutsch`, [TextDirection::Ltr], `Latn`
+    pub fn locale_info(&self, locale: &str) -> LocaleInfo {
+        LocaleInfo {
+            code: locale.to_string(),
+            native_name: self.native_name(locale).map(Cow::into_owned),
+            direction: TextDirection::of(locale),
+            script: crate::metadata::script(locale),
+        }
+    }
+
+    /// Translates a key in the current locale, using the same key resolution and fallbacks as [crate::components::I18nText]
+    ///
+    /// Useful for strings that don't end up in a text entity, e.g. log messages or the clipboard
+    pub fn t(&self, key: &str) -> String {
+        self.t_with(key, &[] as &[(&str, &str)])
+    }
+
+    /// Translates a key in the current locale, replacing its `{name}` placeholders with the provided arguments
+    ///
+    /// `i18n.t_with("greet", &[("name", "Bevy User")])` -> `Hello, Bevy User!`
+    ///
+    /// [crate::prelude::Arg::Key] arguments are replaced by the translation of their key, see [crate::prelude::Arg]
+    pub fn t_with(&self, key: &str, args: &[(&str, impl ToArg)]) -> String {
+        crate::ruby::strip_ruby(self.annotated(key, args)).into_owned()
+    }
+
+    /// Translates a key in the current locale into its plain and ruby annotated segments, e.g. for the furigana of Japanese,
+    /// `漢字《かんじ》を読む` -> `漢字` (`かんじ`), `を読む`
+    ///
+    /// The other translation methods and the text components only keep the base of the annotations, see [RubySegment]
+    pub fn t_ruby(&self, key: &str) -> Vec<RubySegment> {
+        self.t_ruby_with(key, &[] as &[(&str, &str)])
+    }
+
+    /// Translates a key in the current locale into its ruby annotated segments, replacing its `{name}` placeholders with the provided arguments
+    pub fn t_ruby_with(&self, key: &str, args: &[(&str, impl ToArg)]) -> Vec<RubySegment> {
+        crate::ruby::parse_ruby(&self.annotated(key, args))
+    }
+
+    /// Translation of a key in the current locale along with its ruby annotations
+    fn annotated(&self, key: &str, args: &[(&str, impl ToArg)]) -> Cow<'_, str> {
+        let (args, keys) = split_args(args);
+        self.translate(
+            &self.current,
+            key,
+            TranslateOptions {
+                args,
+                keys: &keys,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Translates every element of a list in the current locale, `tips` -> `tips.0`, `tips.1`, ...
+    ///
+    /// The elements are resolved like any other key, so they can also be translated one by one,
+    /// e.g. `I18nText::new("tips.2").with_count(3)` for an element with plural forms
+    pub fn t_list(&self, key: &str) -> Vec<String> {
+        self.t_list_with(key, &[] as &[(&str, &str)])
+    }
+
+    /// Translates every element of a list in the current locale, replacing their `{name}` placeholders with the provided arguments
+    pub fn t_list_with(&self, key: &str, args: &[(&str, impl ToArg)]) -> Vec<String> {
+        let (args, keys) = split_args(args);
+        (0..self.list_len(&self.current, key))
+            .map(|index| {
+                self.translate(
+                    &self.current,
+                    &format!("{}.{}", key, index),
+                    TranslateOptions {
+                        args: args.clone(),
+                        keys: &keys,
+                        ..Default::default()
+                    },
+                )
+            })
+            .map(|translated| crate::ruby::strip_ruby(translated).into_owned())
+            .collect()
+    }
+
+    /// Whether a key is translated in the current locale or one of the fallback locales, see [I18n::contains_key_in]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.contains_key_in(&self.current, key)
+    }
+
+    /// Whether a key is translated in a locale or one of the fallback locales, without translating it or logging it as missing,
+    /// e.g. to hide an optional tooltip instead of showing its key
+    ///
+    /// A key with plural forms or context variants exists when its neutral `key.other` form does, and a list when its first element does
+    pub fn contains_key_in(&self, locale: &str, key: &str) -> bool {
+        std::iter::once(locale)
+            .chain(self.fallbacks.iter().map(String::as_str))
+            .any(|locale| self.is_translated_in(locale, key))
+    }
+
+    /// Whether a key is translated in a locale, or its less specific variants, ignoring the fallback locales, see [I18n::contains_key_in]
+    pub fn is_translated_in(&self, locale: &str, key: &str) -> bool {
+        #[cfg(feature = "pseudolocale")]
+        let locale = if locale == crate::pseudo::PSEUDO_LOCALE {
+            self.pseudo_source()
+        } else {
+            locale
+        };
+        [
+            Cow::Borrowed(key),
+            Cow::Owned(format!("{}.other", key)),
+            Cow::Owned(format!("{}.0", key)),
+        ]
+        .iter()
+        .any(|key| self.lookup_locale(locale, key).is_some())
+    }
+
+    /// Translations of every key of a locale and its fallback locales, sorted by key,
+    /// e.g. to check in a test that keys resolve or to export a review document for the translators
+    ///
+    /// Each key is translated like [I18n::t] does without arguments, so the runtime overrides, references and fallbacks
+    /// are applied and placeholders are kept. Plural forms, context variants and list elements are listed as their own keys
+    pub fn dump(&self, locale: &str) -> BTreeMap<String, String> {
+        #[cfg(feature = "pseudolocale")]
+        let source = if locale == crate::pseudo::PSEUDO_LOCALE {
+            self.pseudo_source()
+        } else {
+            locale
+        };
+        #[cfg(not(feature = "pseudolocale"))]
+        let source = locale;
+        let mut keys = self.keys(source);
+        for fallback in self.fallbacks.iter() {
+            keys.extend(self.keys(fallback));
+        }
+        keys.into_iter()
+            .map(|key| {
+                let translated = self.translate(locale, key, TranslateOptions::default());
+                let translated = crate::ruby::strip_ruby(translated).into_owned();
+                (key.to_string(), translated)
+            })
+            .collect()
+    }
+
+    /// Number of elements of a list in the first locale of the fallback chain that defines it,
+    /// so the lists of different locales aren't mixed when they have different lengths
+    ///
+    /// Logs the list as missing when no locale defines it
+    fn list_len(&self, locale: &str, key: &str) -> usize {
+        #[cfg(feature = "pseudolocale")]
+        let locale = if locale == crate::pseudo::PSEUDO_LOCALE {
+            self.pseudo_source()
+        } else {
+            locale
+        };
+        let has_element = |locale: &str, index: usize| {
+            let element = format!("{}.{}", key, index);
+            self.lookup_locale(locale, &element).is_some()
+                || self
+                    .lookup_locale(locale, &format!("{}.other", element))
+                    .is_some()
+        };
+        let defining = std::iter::once(locale)
+            .chain(self.fallbacks.iter().map(String::as_str))
+            .find(|locale| has_element(locale, 0));
+        let Some(defining) = defining else {
+            self.missing(locale, key);
+            return 0;
+        };
+        (1..).find(|index| !has_element(defining, *index)).unwrap()
+    }
+
+    /// Resolves the translation of a key for a locale, selecting its context variant and plural form when provided,
+    /// and interpolates the arguments
+    ///
+    /// Shared by [I18n::t] and the text components
+    pub(crate) fn translate<'a, 'b>(
+        &'a self,
+        locale: &str,
+        key: &str,
+        options: TranslateOptions<'a, 'b>,
+    ) -> Cow<'a, str> {
+        let TranslateOptions {
+            mut args,
+            #[allow(unused_mut)]
+            mut numbers,
+            keys,
+            context,
+            default,
+            #[cfg(feature = "plurals")]
+            count,
+        } = options;
+        // The words are interpolated in the translation, which adds its own direction marks
+        for (name, key) in keys {
+            let word = self.translate(locale, key, TranslateOptions::default());
+            let word = word
+                .split('\n')
+                .map(|line| line.trim_start_matches(crate::components::utils::RLM))
+                .collect::<Vec<_>>()
+                .join("\n");
+            args.push((name, word));
+        }
+        #[cfg(feature = "pseudolocale")]
+        let pseudo = locale == crate::pseudo::PSEUDO_LOCALE;
+        #[cfg(feature = "pseudolocale")]
+        let locale = if pseudo { self.pseudo_source() } else { locale };
+
+        #[cfg(feature = "plurals")]
+        let category = count.map(|count| {
+            let locale_option = Some(locale.to_string());
+            #[cfg(feature = "numbers")]
+            args.push((
+                "count",
+                crate::components::utils::format_decimal(&locale_option, count, key),
+            ));
+            #[cfg(not(feature = "numbers"))]
+            args.push(("count", count.to_string()));
+            numbers.push(("count", count.to_string().parse().unwrap_or_default()));
+
+            crate::components::utils::plural_category(&locale_option, count, key)
+        });
+        #[cfg(not(feature = "plurals"))]
+        let category: Option<&str> = None;
+
+        // Plain keys that exist in the locale skip building the variants
+        let found = if context.is_none() && category.is_none() {
+            self.lookup_locale(locale, key)
+        } else {
+            None
+        };
+        let found = found.or_else(|| {
+            let mut candidates = vec![];
+            let bases = context
+                .map(|context| format!("{}.{}", key, context))
+                .into_iter()
+                .chain(std::iter::once(key.to_string()));
+            for base in bases {
+                if let Some(category) = category {
+                    candidates.push(format!("{}.{}", base, category));
+                    candidates.push(format!("{}.other", base));
+                    candidates.push(base);
+                } else {
+                    let other = format!("{}.other", base);
+                    candidates.push(base);
+                    candidates.push(other);
+                }
+            }
+            self.lookup_first(locale, &candidates)
+        });
+
+        let translated = found
+            .or_else(|| {
+                bevy::log::debug!("Translation key {} is missing, using its default", key);
+                default.map(Cow::Borrowed)
+            })
+            .map(|translated| {
+                crate::references::resolve_references(key, translated, &|key| {
+                    self.lookup_first(locale, &[key.to_string()])
+                })
+            })
+            .map(|translated| crate::select::evaluate_selects(translated, &args, category));
+        #[cfg(feature = "pseudolocale")]
+        let translated = translated.map(|translated| match pseudo {
+            true => Cow::Owned(crate::pseudo::pseudolocalize(&translated, &self.delimiters)),
+            false => translated,
+        });
+        let translated = translated.unwrap_or_else(|| {
+            let missing = self.missing(locale, key);
+            // Stands out from the pseudolocalized translations
+            #[cfg(feature = "pseudolocale")]
+            if pseudo {
+                return Cow::Owned(format!("[!! {} !!]", key));
+            }
+            missing.into()
+        });
+
+        crate::components::utils::apply_direction(
+            locale,
+            crate::components::utils::interpolate(
+                translated,
+                &args,
+                &numbers,
+                locale,
+                &self.delimiters,
+            ),
+        )
+    }
+
+    /// Locale whose translations are pseudolocalized for the [crate::prelude::PSEUDO_LOCALE],
+    /// the first fallback locale or `en`
+    #[cfg(feature = "pseudolocale")]
+    pub fn pseudo_source(&self) -> &str {
+        self.fallbacks.first().map_or("en", String::as_str)
+    }
+
+    /// Base direction of the text of the current locale
+    pub fn direction(&self) -> TextDirection {
+        TextDirection::of(&self.current)
+    }
+
+    /// Path of the variant of an asset for the current locale, see [I18n::localized_path_in]
+    pub fn localized_path<'a>(&self, path: &'a str) -> &'a str {
+        self.localized_path_in(&self.current, path)
+    }
+
+    /// Path of the variant of an asset for a locale, `images/signpost.png` -> `images/signpost.fr.png`,
+    /// trying the less specific variants of the locale and then the fallback locales before the path itself
+    ///
+    /// The variants are the assets with a locale suffix found in the assets folder at compile time
+    pub fn localized_path_in<'a>(&self, locale: &str, path: &'a str) -> &'a str {
+        self.find_variant(locale, |locale| crate::localized_assets::find(path, locale))
+            .unwrap_or(path)
+    }
+
+    #[cfg(feature = "audio")]
+    /// Asset path of the clip of a localized sound for the current locale, see [I18n::audio_path_in]
+    pub fn audio_path(&self, sound: &str) -> Option<String> {
+        self.audio_path_in(&self.current, sound)
+    }
+
+    #[cfg(feature = "audio")]
+    /// Asset path of the clip of a localized sound for a locale, `voice/greeting` -> `voice/greeting/fr.ogg`,
+    /// trying the less specific variants of the locale, then the fallback locales and finally the `fallback` clip of the folder
+    ///
+    /// The clips are found in the assets folder at compile time, `None` when the folder has none of them
+    pub fn audio_path_in(&self, locale: &str, sound: &str) -> Option<String> {
+        self.find_variant(locale, |locale| crate::audio::find(sound, locale))
+            .or_else(|| crate::audio::find(sound, "fallback"))
+    }
+
+    /// Finds the variant of an asset for the first locale that has one,
+    /// the locale and its less specific variants first and then every fallback locale in order
+    fn find_variant<T>(&self, locale: &str, find: impl Fn(&str) -> Option<T>) -> Option<T> {
+        #[cfg(feature = "pseudolocale")]
+        let locale = if locale == crate::pseudo::PSEUDO_LOCALE {
+            self.pseudo_source()
+        } else {
+            locale
+        };
+        std::iter::once(locale)
+            .chain(self.fallbacks.iter().map(String::as_str))
+            .find_map(|locale| {
+                let mut current = locale;
+                loop {
+                    if let Some(variant) = find(current) {
+                        return Some(variant);
+                    }
+                    current = &current[..current.rfind('-')?];
+                }
+            })
+    }
+
+    /// Best available locale for a list of preferred locales, ordered by preference like an `Accept-Language` header,
+    /// `["pt-BR", "pt", "es", "en"]` -> `pt` when `pt`, `en` and `de` are available
+    ///
+    /// Each preferred locale is matched exactly (case insensitive), then by its less specific variants, `pt-BR` -> `pt`.
+    /// When none of them match, falls back to an available locale of the same language as a preferred one, `pt-BR` -> `pt-PT`
+    ///
+    /// `None` when no available locale matches any of the preferred ones
+    pub fn negotiate(&self, preferred: &[impl AsRef<str>]) -> Option<String> {
+        preferred
+            .iter()
+            .find_map(|locale| self.find_locale(locale.as_ref().trim()))
+            .or_else(|| {
+                preferred.iter().find_map(|locale| {
+                    let language = language_of(locale.as_ref().trim())?;
+                    self.locales
+                        .iter()
+                        .find(|available| language_of(available).as_ref() == Some(&language))
+                        .map(String::as_str)
+                })
+            })
+            .map(str::to_string)
+    }
+
+    /// Finds the available locale matching the requested one,
+    /// either exactly (case insensitive) or by its base language, `de-AT` -> `de`
+    pub(crate) fn find_locale(&self, locale: &str) -> Option<&str> {
+        let locale = locale.replace('_', "-");
+        let mut requested = locale.as_str();
+        loop {
+            if let Some(found) = self
+                .locales
+                .iter()
+                .find(|available| available.eq_ignore_ascii_case(requested))
+            {
+                return Some(found);
+            }
+            requested = &requested[..requested.rfind('-')?];
+        }
+    }
+
+    #[cfg(feature = "numbers")]
+    /// Formats a number with the grouping separators and decimal mark of the current locale
+    ///
+    /// `1234567.5` -> `1,234,567.5` in `en`, `1 234 567,5` in `fr`
+    pub fn format_number(&self, number: impl Into<f64>) -> String {
+        self.format_number_in(&self.current, number)
+    }
+
+    #[cfg(feature = "numbers")]
+    /// Formats a number with the grouping separators and decimal mark of the provided locale
+    pub fn format_number_in(&self, locale: &str, number: impl Into<f64>) -> String {
+        let fixed_decimal = crate::components::utils::f64_to_fd(number.into());
+        crate::components::utils::format_decimal(
+            &Some(locale.to_string()),
+            &fixed_decimal,
+            &fixed_decimal,
+        )
+    }
+
+    #[cfg(feature = "numbers")]
+    /// Formats an amount of money in the provided ISO 4217 currency with the conventions of the current locale
+    ///
+    /// The symbol placement and separators come from the locale, the fraction digits from the currency:
+    /// `$9.99` in `en`, `9,99 €` in `fr`, `￥1,000` in `ja` (a non-breaking space separates the symbol)
+    pub fn format_currency(&self, amount: impl Into<f64>, currency: &str) -> String {
+        self.format_currency_in(&self.current, amount, currency)
+    }
+
+    #[cfg(feature = "numbers")]
+    /// Formats an amount of money in the provided ISO 4217 currency with the conventions of the provided locale
+    pub fn format_currency_in(
+        &self,
+        locale: &str,
+        amount: impl Into<f64>,
+        currency: &str,
+    ) -> String {
+        crate::formatters::format_currency(locale, amount.into(), currency)
+    }
+
+    #[cfg(feature = "numbers")]
+    /// Formats a measurement in SI units with the measurement system of the current locale, see [I18n::measurement_system]
+    ///
+    /// The larger unit is used once the value reaches one of it, with at most one fraction digit:
+    /// `1500.0` with [Unit::Length] -> `1.5 km` in `fr`, `4,921.3 ft` in `en-US`,
+    /// `10.0` with [Unit::Speed] -> `36 km/h` in `fr`, `22.4 mph` in `en-US`
+    pub fn format_unit(&self, value: impl Into<f64>, unit: Unit) -> String {
+        self.format_unit_in(&self.current, value, unit)
+    }
+
+    #[cfg(feature = "numbers")]
+    /// Formats a measurement in SI units with the measurement system of the provided locale, unless it's overridden
+    pub fn format_unit_in(&self, locale: &str, value: impl Into<f64>, unit: Unit) -> String {
+        let system = self
+            .measurement_system
+            .unwrap_or_else(|| MeasurementSystem::of(locale));
+        crate::formatters::format_unit(locale, value.into(), unit, system)
+    }
+
+    #[cfg(feature = "numbers")]
+    /// Measurement system of the measurements, the override or the one of the current locale
+    pub fn measurement_system(&self) -> MeasurementSystem {
+        self.measurement_system
+            .unwrap_or_else(|| MeasurementSystem::of(&self.current))
+    }
+
+    #[cfg(feature = "numbers")]
+    /// Override the measurement system of the current locale, e.g. from a settings menu, `None` to follow the locale
+    pub fn set_measurement_system(&mut self, system: Option<MeasurementSystem>) {
+        self.measurement_system = system;
+    }
+
+    #[cfg(feature = "datetime")]
+    /// Formats the date of a Unix timestamp (in seconds, UTC) for the current locale,
+    /// with the month names and field order of the locale data
+    ///
+    /// `Jan 2, 2024` in `en`, `2 janv. 2024` in `fr`, `2024/01/02` in `ja` with [DateTimeStyle::Medium]
+    pub fn format_date(&self, timestamp: i64, style: DateTimeStyle) -> String {
+        crate::formatters::format_date(&self.current, timestamp, style)
+    }
+
+    #[cfg(feature = "datetime")]
+    /// Formats the time of a Unix timestamp (in seconds, UTC) for the current locale
+    pub fn format_time(&self, timestamp: i64, style: DateTimeStyle) -> String {
+        crate::formatters::format_time(&self.current, timestamp, style)
+    }
+
+    #[cfg(feature = "datetime")]
+    /// Formats both the date and time of a Unix timestamp (in seconds, UTC) for the current locale
+    pub fn format_datetime(&self, timestamp: i64, style: DateTimeStyle) -> String {
+        crate::formatters::format_datetime(&self.current, timestamp, style)
+    }
+
+    #[cfg(feature = "plurals")]
+    /// Formats an offset in seconds from now as a relative time in the current locale,
+    /// with the plural form of the largest unit that fits it (seconds, minutes, hours or days)
+    ///
+    /// Negative offsets are in the past: `-300` -> `5 minutes ago` in `en`, `5 минут назад` in `ru`, `7200` -> `in 2 hours` in `en`
+    pub fn format_relative_time(&self, seconds: i64) -> String {
+        self.format_relative_time_in(&self.current, seconds)
+    }
+
+    #[cfg(feature = "plurals")]
+    /// Formats an offset in seconds from now as a relative time in the provided locale
+    pub fn format_relative_time_in(&self, locale: &str, seconds: i64) -> String {
+        crate::formatters::format_relative_time(locale, seconds)
+    }
+
+    #[cfg(feature = "plurals")]
+    /// Formats a number as an ordinal in the current locale, with the CLDR ordinal plural rules of its language
+    /// rather than the cardinal ones
+    ///
+    /// `1` -> `1st`, `2` -> `2nd`, `3` -> `3rd`, `11` -> `11th`, `22` -> `22nd` in `en`, `1er` and `2e` in `fr`
+    pub fn format_ordinal(&self, number: i64) -> String {
+        self.format_ordinal_in(&self.current, number)
+    }
+
+    #[cfg(feature = "plurals")]
+    /// Formats a number as an ordinal in the provided locale
+    pub fn format_ordinal_in(&self, locale: &str, number: i64) -> String {
+        crate::formatters::format_ordinal(locale, number)
+    }
+
+    #[cfg(feature = "list_format")]
+    /// Joins the items into a sentence with the separators and conjunction of the current locale,
+    /// including the special cases of one and two items
+    ///
+    /// `apples, oranges, and pears` in `en`, `pommes, oranges et poires` in `fr` with [ListStyle::And],
+    /// `apples or pears` in `en` with [ListStyle::Or]
+    pub fn format_list(&self, items: &[impl AsRef<str>], style: ListStyle) -> String {
+        self.format_list_in(&self.current, items, style)
+    }
+
+    #[cfg(feature = "list_format")]
+    /// Joins the items into a sentence with the separators and conjunction of the provided locale
+    pub fn format_list_in(
+        &self,
+        locale: &str,
+        items: &[impl AsRef<str>],
+        style: ListStyle,
+    ) -> String {
+        let items: Vec<&str> = items.iter().map(AsRef::as_ref).collect();
+        crate::formatters::format_list(locale, &items, style)
+    }
+
+    /// Set the ordered fallback chain used when a key is missing in the requested locale
+    pub fn set_fallbacks(&mut self, locales: impl IntoIterator<Item = impl Into<String>>) {
+        self.fallbacks = locales.into_iter().map(Into::into).collect();
+        self.invalidate_translations();
+        bevy::log::debug!("Fallback locales set to {:?}", self.fallbacks);
+    }
+
+    /// Ordered fallback chain used when a key is missing in the requested locale
+    pub fn fallbacks(&self) -> &[String] {
+        &self.fallbacks
+    }
+
+    /// Set what to return once a key is missing in the requested locale and all of its fallbacks
+    pub fn set_missing_key_behavior(&mut self, behavior: MissingKeyBehavior) {
+        self.missing_key_behavior = behavior;
+        self.revision += 1;
+    }
+
+    /// What is returned once a key is missing in the requested locale and all of its fallbacks
+    pub fn missing_key_behavior(&self) -> &MissingKeyBehavior {
+        &self.missing_key_behavior
+    }
+
+    /// Set the delimiters of the interpolation placeholders, e.g. `{{name}}` for translations with literal braces
+    ///
+    /// Only changes how the translations are scanned for placeholders, the translations themselves are kept as-is
+    pub fn set_delimiters(&mut self, delimiters: Delimiters) {
+        self.delimiters = delimiters;
+        self.revision += 1;
+    }
+
+    /// Delimiters of the interpolation placeholders, `{name}` by default
+    pub fn delimiters(&self) -> &Delimiters {
+        &self.delimiters
+    }
+
+    /// Override the translation of a key for a locale, e.g. for user-generated content or to A/B test a copy without a new build
+    ///
+    /// Overrides take precedence over the runtime and embedded translations, including for the region variants of the locale,
+    /// and are kept when switching locales. The texts are translated again on the next update
+    pub fn set_override(
+        &mut self,
+        locale: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) {
+        self.overrides
+            .entry(locale.into())
+            .or_default()
+            .insert(key.into(), value.into());
+        self.invalidate_translations();
+    }
+
+    /// Remove the override of a key for a locale, returning its value if it was overridden
+    pub fn clear_override(&mut self, locale: &str, key: &str) -> Option<String> {
+        let value = self.overrides.get_mut(locale)?.remove(key)?;
+        self.invalidate_translations();
+        Some(value)
+    }
+
+    /// Remove the overrides of every key and locale
+    pub fn clear_overrides(&mut self) {
+        if !self.overrides.is_empty() {
+            self.overrides.clear();
+            self.invalidate_translations();
+        }
+    }
+
+    /// Override of a key for a locale, see [I18n::set_override]
+    pub fn get_override(&self, locale: &str, key: &str) -> Option<&str> {
+        self.overrides.get(locale)?.get(key).map(String::as_str)
+    }
+
+    /// Display the keys of the texts instead of their translations, `«menu.start»`, to find which key a text comes from
+    ///
+    /// The texts pinned to a locale also display it, `«menu.start@ja»`. The texts are translated again on the next update,
+    /// so it can be toggled while the app is running, e.g. from a debug key binding
+    pub fn set_show_keys(&mut self, show_keys: bool) {
+        if self.show_keys != show_keys {
+            self.show_keys = show_keys;
+            self.revision += 1;
+        }
+    }
+
+    /// Whether the texts display their keys instead of their translations, see [I18n::set_show_keys]
+    pub fn show_keys(&self) -> bool {
+        self.show_keys
+    }
+
+    /// Translates every text again on the next update, including the ones pinned to a locale,
+    /// and drops the [ResolvedCache] since the translations changed
+    fn invalidate_translations(&mut self) {
+        self.revision += 1;
+        self.resolved = ResolvedCache::new(&self.locales);
+    }
+
+    pub(crate) fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Number of times a missing translation key was hit this session
+    pub fn missing_keys_count(&self) -> usize {
+        self.missing_keys.load(Ordering::Relaxed)
+    }
+
+    /// Forgets which missing keys were already logged, so they are warned about again the next time they are hit,
+    /// e.g. after reloading the locale files
+    ///
+    /// Missing keys are only logged once per session otherwise
+    pub fn reset_missing_key_warnings(&self) {
+        self.warned_missing_keys
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Takes the missing keys hit since the last call, see [MissingKeyLog]
+    #[cfg(feature = "missing_key_log")]
+    pub(crate) fn take_missing_keys(&self) -> Vec<MissingKey> {
+        std::mem::take(
+            &mut *self
+                .pending_missing_keys
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        )
+    }
+
+    /// Resolves a key that's missing in the requested locale and all of its fallbacks
+    /// according to the configured [MissingKeyBehavior]
+    pub(crate) fn missing(&self, locale: &str, key: &str) -> String {
+        self.missing_keys.fetch_add(1, Ordering::Relaxed);
+        let first_hit = self
+            .warned_missing_keys
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert((locale.to_string(), key.to_string()));
+        if first_hit {
+            bevy::log::warn!("Translation key {} is missing for {}", key, locale);
+        }
+        #[cfg(feature = "missing_key_log")]
+        self.pending_missing_keys
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(MissingKey {
+                key: key.to_string(),
+                locale: locale.to_string(),
+            });
+        match &self.missing_key_behavior {
+            MissingKeyBehavior::ReturnKey => key.to_string(),
+            MissingKeyBehavior::Empty => String::new(),
+            MissingKeyBehavior::Panic => {
+                panic!("Translation key {} is missing for {}", key, locale)
+            }
+            MissingKeyBehavior::Placeholder(placeholder) => placeholder.clone(),
+        }
+    }
+
+    /// Looks up the raw translation of the first key found, trying every key in the requested locale first
+    /// (including its less specific variants, `pt-BR` -> `pt`) and then in every fallback locale in order,
+    /// so a neutral form is preferred over a variant of another language
+    ///
+    /// The translations of the available locales are resolved on their first lookup, see [ResolvedCache]
+    pub(crate) fn lookup_first(&self, locale: &str, keys: &[String]) -> Option<Cow<'_, str>> {
+        if let Some(table) = self.resolved.get(locale, || self.resolve(locale)) {
+            return keys
+                .iter()
+                .enumerate()
+                .filter_map(|(index, key)| {
+                    let (rank, value) = table.get(key)?;
+                    Some(((*rank, index), value))
+                })
+                .min_by_key(|(order, _)| *order)
+                .map(|(_, value)| Cow::Borrowed(value.as_ref()));
+        }
+        keys.iter()
+            .find_map(|key| self.lookup_locale(locale, key))
+            .or_else(|| {
+                self.fallbacks.iter().find_map(|fallback| {
+                    bevy::log::debug!(
+                        "Keys {:?} not found for {}, trying {}",
+                        keys,
+                        locale,
+                        fallback
+                    );
+                    keys.iter()
+                        .find_map(|key| self.lookup_locale(fallback, key))
+                })
+            })
+    }
+
+    /// Looks up the raw translation of a key for a locale and its less specific variants,
+    /// the translations loaded at runtime take precedence over the embedded ones
+    ///
+    /// The translations of the available locales are resolved on their first lookup, see [ResolvedCache]
+    fn lookup_locale(&self, locale: &str, key: &str) -> Option<Cow<'_, str>> {
+        if let Some(table) = self.resolved.get(locale, || self.resolve(locale)) {
+            return table
+                .get(key)
+                .filter(|(rank, _)| *rank == 0)
+                .map(|(_, value)| Cow::Borrowed(value.as_ref()));
+        }
+        self.lookup_source(locale, key).map(|value| match value {
+            Source::Runtime(value) | Source::Embedded(value) => Cow::Borrowed(value),
+        })
+    }
+
+    /// Looks up the raw translation of a key for a locale and its less specific variants without the [ResolvedCache]
+    fn lookup_source(&self, locale: &str, key: &str) -> Option<Source<'_>> {
+        let mut current = locale;
+        loop {
+            if let Some(value) = self.overrides.get(current).and_then(|table| table.get(key)) {
+                return Some(Source::Runtime(value));
+            }
+            if let Some(value) = self.runtime.get(current).and_then(|table| table.get(key)) {
+                return Some(Source::Runtime(value));
+            }
+            if let Some(value) = crate::roots::translate(current, key) {
+                return Some(Source::Embedded(value));
+            }
+            #[cfg(any(feature = "fluent", feature = "gettext", feature = "csv"))]
+            if let Some(value) = crate::backends::translate(current, key) {
+                return Some(Source::Embedded(value));
+            }
+            if let Some(value) = crate::_RUST_I18N_BACKEND.translate(current, key) {
+                return Some(Source::Embedded(value));
+            }
+            if let Some(value) = crate::lists::translate(current, key) {
+                return Some(Source::Embedded(value));
+            }
+            current = &current[..current.rfind('-')?];
+        }
+    }
+
+    /// Keys of a locale and its less specific variants, embedded or loaded at runtime
+    fn keys(&self, locale: &str) -> HashSet<&str> {
+        let mut keys = HashSet::new();
+        let mut current = locale;
+        loop {
+            keys.extend(crate::resolved::embedded_keys(current).iter().copied());
+            for table in [self.overrides.get(current), self.runtime.get(current)]
+                .into_iter()
+                .flatten()
+            {
+                keys.extend(table.keys().map(String::as_str));
+            }
+            match current.rfind('-') {
+                Some(index) => current = &current[..index],
+                None => break,
+            }
+        }
+        keys
+    }
+
+    /// Resolves every key of a locale, its less specific variants and its fallback locales, copying the runtime translations
+    fn resolve(&self, locale: &str) -> ResolvedTable {
+        let mut table = ResolvedTable::new();
+        let chain = std::iter::once(locale).chain(self.fallbacks.iter().map(String::as_str));
+        for (rank, current) in chain.enumerate() {
+            for key in self.keys(current) {
+                if table.contains_key(key) {
+                    continue;
+                }
+                let Some(value) = self.lookup_source(current, key) else {
+                    continue;
+                };
+                let value = match value {
+                    Source::Runtime(value) => Cow::Owned(value.to_string()),
+                    Source::Embedded(value) => Cow::Borrowed(value),
+                };
+                table.insert(key.to_string(), (rank, value));
+            }
+        }
+        table
+    }
+
+    #[cfg(feature = "fonts")]
+    /// Unique characters that the translations of a locale can render, including its less specific variants
+    /// and its fallback locales, used to warm up the glyph atlas
+    pub(crate) fn glyphs(&self, locale: &str) -> String {
+        let mut locales = vec![];
+        let mut current = locale;
+        loop {
+            locales.push(current);
+            match current.rfind('-') {
+                Some(index) => current = &current[..index],
+                None => break,
+            }
+        }
+        locales.extend(self.fallbacks.iter().map(String::as_str));
+
+        let mut glyphs = std::collections::BTreeSet::new();
+        for locale in locales {
+            glyphs.extend(crate::glyphs::embedded(locale).unwrap_or_default().chars());
+            if let Some(table) = self.runtime.get(locale) {
+                glyphs.extend(
+                    table
+                        .values()
+                        .flat_map(|value| value.chars())
+                        .filter(|c| !c.is_whitespace() && !c.is_control()),
+                );
+            }
+        }
+        glyphs.into_iter().collect()
+    }
+
+    /// Replaces the translations that were loaded at runtime
+    #[cfg(feature = "runtime_translations")]
+    pub(crate) fn set_runtime_translations(
+        &mut self,
+        translations: HashMap<String, HashMap<String, String>>,
+    ) {
+        bevy::log::debug!(
+            "Runtime translations set for {} locales",
+            translations.len()
+        );
+        for locale in translations.keys() {
+            if !self.locales.contains(locale) {
+                self.locales.push(locale.clone());
+            }
+        }
+        self.locales.sort();
+        self.runtime = translations;
+        self.invalidate_translations();
+    }
+}
+
+/// Arguments and variant selectors of a translation, see [I18n::translate]
+///
+/// Without a count the plural forms are skipped, `other` is still used as the neutral form
+#[derive(Default)]
+pub(crate) struct TranslateOptions<'a, 'b> {
+    /// Values of the placeholders
+    pub(crate) args: Vec<(&'b str, String)>,
+    /// Numeric values of the placeholders with a format spec, `{damage:0.0}`
+    pub(crate) numbers: Vec<(&'b str, f64)>,
+    /// Placeholders replaced by the translation of a key in the same locale
+    pub(crate) keys: &'b [(&'b str, String)],
+    /// Context variant of the key, `key.female`
+    pub(crate) context: Option<&'b str>,
+    /// Text used instead of the [MissingKeyBehavior] when none of the variants exist
+    pub(crate) default: Option<&'a str>,
+    /// Count selecting the plural form, also interpolated as `{count}`
+    #[cfg(feature = "plurals")]
+    pub(crate) count: Option<&'b fixed_decimal::FixedDecimal>,
 }
 
 impl Default for I18n {
     fn default() -> Self {
+        #[allow(unused_mut)]
+        // `rust-i18n` reads the files of the locale folders, `en/menu.yml`, as the `menu` locale
+        let mut locales: Vec<String> = rust_i18n::available_locales!()
+            .into_iter()
+            .filter(|locale| crate::resolved::locales().any(|l| l == *locale))
+            .map(|s| s.into())
+            .collect();
+        for locale in crate::roots::locales() {
+            if !locales.iter().any(|l| l == locale) {
+                locales.push(locale.to_string());
+            }
+        }
+        #[cfg(feature = "external_translations")]
+        for locale in crate::resolved::locales() {
+            if !locales.iter().any(|l| l == locale) {
+                locales.push(locale.to_string());
+            }
+        }
+        locales.sort();
+        #[cfg(any(feature = "fluent", feature = "gettext", feature = "csv"))]
+        {
+            for locale in crate::backends::locales() {
+                if !locales.iter().any(|l| l == locale) {
+                    locales.push(locale.to_string());
+                }
+            }
+            locales.sort();
+        }
+        #[cfg(feature = "pseudolocale")]
+        {
+            locales.push(crate::pseudo::PSEUDO_LOCALE.to_string());
+            locales.sort();
+        }
+        let resolved = ResolvedCache::new(&locales);
         Self {
             current: rust_i18n::locale().to_string(),
-            locales: rust_i18n::available_locales!()
-                .into_iter()
-                .map(|s| s.into())
-                .collect(),
+            locales,
+            fallbacks: vec![],
+            missing_key_behavior: MissingKeyBehavior::default(),
+            delimiters: Delimiters::default(),
+            missing_keys: AtomicUsize::new(0),
+            warned_missing_keys: Default::default(),
+            #[cfg(feature = "missing_key_log")]
+            pending_missing_keys: Default::default(),
+            #[cfg(feature = "numbers")]
+            measurement_system: None,
+            runtime: HashMap::new(),
+            overrides: HashMap::new(),
+            show_keys: false,
+            resolved,
+            revision: 0,
+        }
+    }
+}
+
+/// Read-only snapshot of the [I18n] resource that's cheap to clone and can be used off the main thread,
+/// e.g. to generate localized text in the tasks of the `AsyncComputeTaskPool`
+///
+/// Derefs to [I18n], with the locale captured by [I18n::handle] or [I18n::handle_in] as its current locale.
+/// Later changes to the resource, e.g. switching the locale, don't affect the handle,
+/// and the missing keys hit through it aren't counted by the resource
+///
+/// # Example
+/// ```
+/// use bevy::{prelude::*, tasks::AsyncComputeTaskPool};
+/// use bevy_simple_i18n::prelude::*;
+///
+/// fn generate_quest(i18n_res: Res<I18n>) {
+///     let i18n = i18n_res.handle();
+///     AsyncComputeTaskPool::get()
+///         .spawn(async move { i18n.t_with("greet", &[("name", "Bevy User")]) })
+///         .detach();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct I18nHandle(Arc<I18n>);
+
+impl Deref for I18nHandle {
+    type Target = I18n;
+
+    fn deref(&self) -> &I18n {
+        &self.0
+    }
+}
+
+/// Translation key that was missing in a locale and all of its fallbacks
+#[cfg(feature = "missing_key_log")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect)]
+pub struct MissingKey {
+    pub key: String,
+    pub locale: String,
+}
+
+/// Every missing translation key hit since the log was last cleared, with the number of times it was hit,
+/// useful for localization QA, requires the `missing_key_log` feature
+///
+/// Updated at the end of every frame, with the keys of the text entities and of the [I18n] methods
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_i18n::prelude::*;
+///
+/// fn report(mut log: ResMut<MissingKeyLog>) {
+///     for (missing, hits) in log.iter() {
+///         warn!("{} is missing for {} ({} hits)", missing.key, missing.locale, hits);
+///     }
+///     log.clear();
+/// }
+/// ```
+#[cfg(feature = "missing_key_log")]
+#[derive(Debug, Default, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct MissingKeyLog {
+    entries: std::collections::BTreeMap<MissingKey, usize>,
+}
+
+#[cfg(feature = "missing_key_log")]
+impl MissingKeyLog {
+    /// Missing keys sorted by key and locale, with the number of times they were hit
+    pub fn iter(&self) -> impl Iterator<Item = (&MissingKey, usize)> {
+        self.entries.iter().map(|(missing, hits)| (missing, *hits))
+    }
+
+    /// Whether the key was missing for the locale
+    pub fn contains(&self, key: &str, locale: &str) -> bool {
+        self.entries.contains_key(&MissingKey {
+            key: key.to_string(),
+            locale: locale.to_string(),
+        })
+    }
+
+    /// Number of distinct missing keys
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub(crate) fn extend(&mut self, missing_keys: Vec<MissingKey>) {
+        for missing in missing_keys {
+            *self.entries.entry(missing).or_default() += 1;
+        }
+    }
+}
+
+/// Style variant of a dynamic font, discovered from the file name suffix of the font files
+///
+/// `en.ttf` -> [FontStyle::Regular], `en.bold.ttf` -> [FontStyle::Bold],
+/// `en.italic.ttf` -> [FontStyle::Italic], `en.bold_italic.ttf` -> [FontStyle::BoldItalic],
+/// `en.300.ttf` and `en.300_italic.ttf` -> [FontStyle::Weight]
+#[cfg(feature = "fonts")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum FontStyle {
+    #[default]
+    Regular,
+    Bold,
+    Italic,
+    BoldItalic,
+    /// A numeric weight, from `100` (thin) to `900` (black), see [FontStyle::weighted]
+    Weight {
+        weight: u16,
+        italic: bool,
+    },
+}
+
+#[cfg(feature = "fonts")]
+impl FontStyle {
+    /// Style of a numeric weight, `400` and `700` are the regular and bold styles
+    pub fn weighted(weight: u16, italic: bool) -> Self {
+        match (weight, italic) {
+            (400, false) => Self::Regular,
+            (700, false) => Self::Bold,
+            (400, true) => Self::Italic,
+            (700, true) => Self::BoldItalic,
+            (weight, italic) => Self::Weight { weight, italic },
+        }
+    }
+
+    /// Numeric weight of the style, `400` for the regular styles and `700` for the bold ones
+    pub fn weight(&self) -> u16 {
+        match self {
+            Self::Regular | Self::Italic => 400,
+            Self::Bold | Self::BoldItalic => 700,
+            Self::Weight { weight, .. } => *weight,
+        }
+    }
+
+    pub fn is_italic(&self) -> bool {
+        matches!(
+            self,
+            Self::Italic | Self::BoldItalic | Self::Weight { italic: true, .. }
+        )
+    }
+
+    /// Parses the style suffix of a font file name, `None` for an unknown suffix
+    pub(crate) fn from_suffix(suffix: Option<&str>) -> Option<Self> {
+        match suffix {
+            None | Some("regular") => Some(Self::Regular),
+            Some("bold") => Some(Self::Bold),
+            Some("italic") => Some(Self::Italic),
+            Some("bold_italic") | Some("bolditalic") => Some(Self::BoldItalic),
+            Some(suffix) => {
+                let (weight, italic) = match suffix.strip_suffix("_italic") {
+                    Some(weight) => (weight, true),
+                    None => (suffix, false),
+                };
+                let weight = weight.parse::<u16>().ok()?;
+                (1..=1000)
+                    .contains(&weight)
+                    .then(|| Self::weighted(weight, italic))
+            }
         }
     }
 }
 
 /// Internal struct for managing fonts for a specific font family.
 ///
-/// It attempts to find a specified font for the most specific locale.
+/// It attempts to find a specified font for the most specific locale and the requested style.
 ///
 /// If unsuccessful, it will split the locale at the last `-` and try again.
 ///
 /// `en-US` -> `en` -> `fallback`
 ///
-/// If still unsuccessful, it will return the fallback font.
+/// A missing style variant falls back to the font of the locale with the nearest weight, the upright fonts last for an italic style,
+/// before the fallback font, since a styled fallback font usually lacks the glyphs of the locale.
+/// A variable font serves every weight of its `wght` axis, though a static file of the exact weight is preferred
+/// since Bevy renders the default instance of a variable font.
+///
+/// If still unsuccessful, it will return the fallback font of the requested style, then the first regular fallback font,
+/// and Bevy's default font when the family has no `fallback.ttf`.
+///
+/// The fallback fonts are also used in order for the characters that the font of the locale can't render,
+/// see [FontFolder::glyph_fallbacks]
+#[cfg(feature = "fonts")]
 #[derive(Debug, Default, Reflect)]
 pub(crate) struct FontFolder {
-    pub(crate) fallback: Handle<Font>,
-    pub(crate) fonts: HashMap<String, Handle<Font>>,
+    /// Ordered chain of the regular fallback fonts, `fallback.ttf` followed by the `fallback.<name>.ttf` files by name
+    pub(crate) fallbacks: Vec<Handle<Font>>,
+    pub(crate) styled_fallbacks: HashMap<FontStyle, Handle<Font>>,
+    pub(crate) fonts: HashMap<(String, FontStyle), Handle<Font>>,
+    /// Font size multipliers of the locales, read from the `scales.toml` file of the folder
+    pub(crate) scales: HashMap<String, f32>,
+    /// Line height and letter spacing of the locales, read from the `scales.toml` file of the folder
+    pub(crate) spacings: HashMap<String, crate::components::TextSpacing>,
+    /// Asset paths of the locale fonts that are loaded once their locale is selected, with lazy fonts
+    pub(crate) unloaded: HashMap<(String, FontStyle), String>,
+    /// Range of the `wght` axis of the variable locale fonts, found by the build script
+    pub(crate) variable_weights: HashMap<(String, FontStyle), (u16, u16)>,
 }
 
+#[cfg(feature = "fonts")]
 impl FontFolder {
-    pub(crate) fn get(&self, locale: impl Into<String>) -> Handle<Font> {
+    pub(crate) fn get(&self, locale: impl Into<String>, style: FontStyle) -> Handle<Font> {
         let locale: String = locale.into();
-        let mut locale = locale.as_str();
 
-        bevy::log::debug!("Evaluating font for {} locale", locale);
+        bevy::log::debug!(
+            "Evaluating font for {} locale with {:?} style",
+            locale,
+            style
+        );
+        let italics = if style.is_italic() {
+            &[true, false][..]
+        } else {
+            &[false][..]
+        };
+        for italic in italics {
+            for exact in [true, false] {
+                if let Some(font) = self.get_locale(&locale, style.weight(), *italic, exact) {
+                    return font;
+                }
+            }
+            bevy::log::debug!("{:?} style was not found, trying the upright fonts", style);
+        }
+        if let Some(font) = self.styled_fallbacks.get(&style) {
+            bevy::log::debug!("Returning the {:?} fallback font", style);
+            return font.clone();
+        }
+
+        bevy::log::debug!("Returning the fallback font");
+        self.fallbacks.first().cloned().unwrap_or_default()
+    }
+
+    /// Fonts used in order to render the characters that are missing in the font of the locale,
+    /// the styled fallback font, the chain of fallback fonts,
+    /// and then the fonts of the other locales, e.g. `ja.ttf` for a Japanese name in a French UI
+    ///
+    /// Empty when the family has no fallback font
+    pub(crate) fn glyph_fallbacks(&self, style: FontStyle) -> Vec<Handle<Font>> {
+        let mut fonts: Vec<Handle<Font>> = self
+            .styled_fallbacks
+            .get(&style)
+            .into_iter()
+            .chain(self.fallbacks.iter())
+            .cloned()
+            .collect();
+        if fonts.is_empty() {
+            return fonts;
+        }
+        let mut locales: Vec<&(String, FontStyle)> = self
+            .fonts
+            .keys()
+            .filter(|(_, font_style)| *font_style == style || *font_style == FontStyle::Regular)
+            .collect();
+        locales.sort_by_key(|(locale, font_style)| (*font_style != style, locale.clone()));
+
+        for key in locales {
+            let font = &self.fonts[key];
+            if !fonts.contains(font) {
+                fonts.push(font.clone());
+            }
+        }
+        fonts
+    }
+
+    /// Font size multiplier of the most specific locale that has one, `1.0` when none does
+    pub(crate) fn scale(&self, locale: &str) -> f32 {
+        let mut locale = locale;
+        loop {
+            if let Some(scale) = self.scales.get(locale) {
+                return *scale;
+            }
+            match locale.rfind('-') {
+                Some(index) => locale = &locale[..index],
+                None => return 1.0,
+            }
+        }
+    }
+
+    /// Line height and letter spacing of the most specific locales that set them
+    pub(crate) fn spacing(&self, locale: &str) -> crate::components::TextSpacing {
+        let mut spacing = crate::components::TextSpacing::default();
+        let mut locale = locale;
+        loop {
+            if let Some(locale_spacing) = self.spacings.get(locale) {
+                spacing.line_height = spacing.line_height.or(locale_spacing.line_height);
+                spacing.letter_spacing = spacing.letter_spacing.or(locale_spacing.letter_spacing);
+            }
+            match locale.rfind('-') {
+                Some(index) => locale = &locale[..index],
+                None => return spacing,
+            }
+        }
+    }
+
+    /// Removes every occurrence of a font, e.g. one that failed to load
+    pub(crate) fn remove(&mut self, id: AssetId<Font>) {
+        self.fonts.retain(|_, font| font.id() != id);
+        self.styled_fallbacks.retain(|_, font| font.id() != id);
+        self.fallbacks.retain(|font| font.id() != id);
+    }
+
+    /// Font of the most specific locale that has one of the weight, or the nearest weight when it's not exact,
+    /// preferring the heavier font for the weights from `400` and the lighter one below
+    fn get_locale(
+        &self,
+        locale: &str,
+        weight: u16,
+        italic: bool,
+        exact: bool,
+    ) -> Option<Handle<Font>> {
+        let mut locale = locale;
         while !locale.is_empty() {
-            if let Some(font) = self.fonts.get(locale) {
+            let nearest = self
+                .fonts
+                .iter()
+                .filter(|((font_locale, style), _)| {
+                    font_locale == locale && style.is_italic() == italic
+                })
+                .filter_map(|(key, font)| {
+                    let variable = self.variable_weights.get(key);
+                    let (min, max) = variable
+                        .copied()
+                        .unwrap_or((key.1.weight(), key.1.weight()));
+                    let distance = min.saturating_sub(weight).max(weight.saturating_sub(max));
+                    let lighter = max < weight;
+                    (!exact || distance == 0).then_some((
+                        (
+                            distance,
+                            lighter == (weight >= 400),
+                            variable.is_some(),
+                            min,
+                        ),
+                        font,
+                    ))
+                })
+                .min_by_key(|(key, _)| *key);
+            if let Some((_, font)) = nearest {
                 bevy::log::debug!("Font for {} locale found", locale);
-                return font.clone();
+                return Some(font.clone());
             }
             if let Some(index) = locale.rfind('-') {
                 bevy::log::debug!("Font for {} locale was not found", locale);
@@ -90,19 +1598,39 @@ impl FontFolder {
                 break;
             }
         }
-
-        bevy::log::debug!("Returning the fallback font");
-        self.fallback.clone()
+        None
     }
 }
 
+/// Language of a locale, `pt` for `pt-BR`
+fn language_of(locale: &str) -> Option<icu_locid::subtags::Language> {
+    let locale = locale.replace('_', "-").parse::<Locale>().ok()?;
+    Some(locale.id.language)
+}
+
+/// Whether the fonts of a locale are used by another, `zh` for `zh-TW`
+#[cfg(feature = "fonts")]
+pub(crate) fn is_locale_of(font_locale: &str, locale: &str) -> bool {
+    locale
+        .strip_prefix(font_locale)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+}
+
 /// Resource for managing fonts for different font families
+///
+/// The font size of the text entities with an [crate::prelude::I18nFont] is multiplied by the scale of their locale,
+/// e.g. to render CJK glyphs smaller than Latin ones at the same nominal size
+#[cfg(feature = "fonts")]
 #[derive(Debug, Reflect, Default, Resource)]
 #[reflect(Resource)]
-pub(crate) struct FontManager {
+pub struct FontManager {
     pub(crate) fonts: HashMap<String, FontFolder>,
+    /// `(family, locale)` pairs that were already warned about, so each missing family is only logged once
+    #[reflect(ignore)]
+    warned_missing_families: Mutex<HashSet<(String, String)>>,
 }
 
+#[cfg(feature = "fonts")]
 impl FontManager {
     pub(crate) fn insert(&mut self, family: impl Into<String>, font_folder: FontFolder) {
         let family: String = family.into();
@@ -110,19 +1638,197 @@ impl FontManager {
         self.fonts.insert(family, font_folder);
     }
 
-    pub(crate) fn get(&self, family: &str, locale: Option<String>) -> Handle<Font> {
+    /// Whether a font family was found in the fonts folder, e.g. to validate the [crate::prelude::I18nFont] families at startup
+    pub fn has_family(&self, family: &str) -> bool {
+        self.fonts.contains_key(family)
+    }
+
+    /// Font of a family for a locale, Bevy's default font when the family doesn't exist
+    pub(crate) fn get(
+        &self,
+        family: &str,
+        locale: Option<String>,
+        style: FontStyle,
+    ) -> Handle<Font> {
         let locale = locale.unwrap_or(rust_i18n::locale().to_string());
         if let Some(folder) = self.fonts.get(family) {
             bevy::log::debug!("Found font family: {}", family);
-            folder.get(locale)
+            folder.get(locale, style)
         } else {
-            bevy::log::debug!("Font {} was not found, using default", family);
+            let first_hit = self
+                .warned_missing_families
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert((family.to_string(), locale.clone()));
+            if first_hit {
+                let mut families: Vec<&str> = self.fonts.keys().map(String::as_str).collect();
+                families.sort_unstable();
+                bevy::log::warn!(
+                    "Font family {} was not found for the {} locale, using Bevy's default font instead, available families: {:?}",
+                    family,
+                    locale,
+                    families
+                );
+            }
             Handle::<Font>::default()
         }
     }
+
+    pub(crate) fn glyph_fallbacks(&self, family: &str, style: FontStyle) -> Vec<Handle<Font>> {
+        self.fonts
+            .get(family)
+            .map(|folder| folder.glyph_fallbacks(style))
+            .unwrap_or_default()
+    }
+
+    /// Font size multiplier of a locale in a font family, `1.0` when it has none
+    ///
+    /// Locales without a scale use the one of their parent locale, `zh-TW` -> `zh`
+    pub fn font_scale(&self, family: &str, locale: &str) -> f32 {
+        self.fonts
+            .get(family)
+            .map_or(1.0, |folder| folder.scale(locale))
+    }
+
+    /// Line height and letter spacing of a locale in a font family, both `None` when it has none
+    ///
+    /// Locales without them use the ones of their parent locale, `zh-TW` -> `zh`
+    pub fn text_spacing(&self, family: &str, locale: &str) -> crate::components::TextSpacing {
+        self.fonts
+            .get(family)
+            .map(|folder| folder.spacing(locale))
+            .unwrap_or_default()
+    }
+
+    /// Drops the font handles of the locales that aren't used by any of the provided locales,
+    /// so their memory is reclaimed once no text entity uses them anymore
+    ///
+    /// The fallback fonts are always kept, the dropped fonts are loaded again once their locale is selected
+    /// when the plugin is built with [crate::plugin::I18nPlugin::with_lazy_fonts] or [crate::plugin::I18nPlugin::with_font_unloading]
+    pub fn unload_fonts_except(&mut self, locales: &[&str]) {
+        for (family, folder) in self.fonts.iter_mut() {
+            let inactive: Vec<(String, FontStyle)> = folder
+                .fonts
+                .keys()
+                .filter(|(font_locale, _)| {
+                    !locales
+                        .iter()
+                        .any(|locale| is_locale_of(font_locale, locale))
+                })
+                .cloned()
+                .collect();
+            for key in inactive {
+                let font = folder.fonts.remove(&key).unwrap();
+                bevy::log::debug!("Unloading {} font of the {} family", key.0, family);
+                if let Some(path) = font.path() {
+                    folder.unloaded.insert(key, path.to_string());
+                }
+            }
+        }
+    }
+
+    /// Sets the font size multiplier of a locale in a font family, overriding the one of its `scales.toml` file
+    ///
+    /// Does nothing when the font family doesn't exist
+    pub fn set_font_scale(&mut self, family: &str, locale: impl Into<String>, scale: f32) {
+        if let Some(folder) = self.fonts.get_mut(family) {
+            folder.scales.insert(locale.into(), scale);
+        } else {
+            bevy::log::warn!("Font family {} was not found, its scale is not set", family);
+        }
+    }
+
+    /// Sets the line height and letter spacing of a locale in a font family, overriding the ones of its `scales.toml` file
+    ///
+    /// Does nothing when the font family doesn't exist
+    pub fn set_text_spacing(
+        &mut self,
+        family: &str,
+        locale: impl Into<String>,
+        spacing: crate::components::TextSpacing,
+    ) {
+        if let Some(folder) = self.fonts.get_mut(family) {
+            folder.spacings.insert(locale.into(), spacing);
+        } else {
+            bevy::log::warn!(
+                "Font family {} was not found, its spacing is not set",
+                family
+            );
+        }
+    }
+}
+
+/// Font sizes that the glyph atlas is warmed up with, and the locales that were already warmed up,
+/// see [crate::plugin::I18nPlugin::with_glyph_warmup]
+#[cfg(feature = "fonts")]
+#[derive(Debug, Default, Resource)]
+pub(crate) struct GlyphWarmup {
+    pub(crate) font_sizes: Vec<f32>,
+    /// Locale that is warmed up during the current [FontsLoading] phase
+    pub(crate) locale: String,
+    pub(crate) warmed: HashSet<String>,
+    /// Hidden text entities rendering the glyphs of the locale, and the number of frames since they were spawned
+    pub(crate) texts: Vec<bevy::ecs::entity::Entity>,
+    pub(crate) frames: u32,
+}
+
+#[cfg(feature = "fonts")]
+impl GlyphWarmup {
+    /// Whether the [FontsLoading] phase has to wait for the glyphs of the locale to be rendered
+    pub(crate) fn is_pending(&self) -> bool {
+        !self.warmed.contains(&self.locale) || !self.texts.is_empty()
+    }
 }
 
 /// Hacky resource to signal that fonts are still loading
+#[cfg(feature = "fonts")]
 #[derive(Debug, Reflect, Default, Resource)]
 #[reflect(Resource)]
-pub(crate) struct FontsLoading;
+pub(crate) struct FontsLoading {
+    /// Families whose deferred fonts are loading, only their texts are translated again once they're loaded,
+    /// every text is when `None`
+    pub(crate) families: Option<Vec<String>>,
+}
+
+/// Configuration of the [crate::plugin::I18nPlugin] that is read by its systems
+#[cfg(feature = "fonts")]
+#[derive(Debug, Default, Resource)]
+pub(crate) struct I18nConfig {
+    pub(crate) fonts_path: Option<String>,
+    pub(crate) lazy_fonts: bool,
+    pub(crate) font_loading_timeout: Option<std::time::Duration>,
+}
+
+/// Base direction of the active locale, updated when the [crate::events::LocaleChanged] event is sent
+///
+/// The [crate::components::MirrorLayout] subtrees are mirrored while it's [TextDirection::Rtl],
+/// and a [crate::events::DirectionChanged] event is sent when it changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct LayoutDirection(pub(crate) TextDirection);
+
+impl Deref for LayoutDirection {
+    type Target = TextDirection;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromWorld for LayoutDirection {
+    fn from_world(world: &mut World) -> Self {
+        Self(world.resource::<I18n>().direction())
+    }
+}
+
+/// Keeps track of the locale that the text entities were last translated to,
+/// used to detect when the [I18n] locale actually changes value
+#[derive(Debug, Reflect, Resource)]
+#[reflect(Resource)]
+pub(crate) struct ActiveLocale(pub(crate) String);
+
+impl FromWorld for ActiveLocale {
+    fn from_world(world: &mut World) -> Self {
+        Self(world.resource::<I18n>().current().to_string())
+    }
+}
@@ -0,0 +1,19 @@
+//! Translations of the extra translation roots, e.g. DLCs and mods, merged over the embedded ones by the build script,
+//! along with the translations of the locale folders that `rust-i18n` doesn't embed
+
+include!(concat!(env!("OUT_DIR"), "/bevy_simple_i18n_roots.rs"));
+
+/// Translation of a key from the extra translation roots of a locale
+pub(crate) fn translate(locale: &str, key: &str) -> Option<&'static str> {
+    let index = ROOT_TRANSLATIONS
+        .binary_search_by_key(&locale, |(l, _)| l)
+        .ok()?;
+    let table = ROOT_TRANSLATIONS[index].1;
+    let index = table.binary_search_by_key(&key, |(k, _)| k).ok()?;
+    Some(table[index].1)
+}
+
+/// Locales that have translations in the extra translation roots
+pub(crate) fn locales() -> impl Iterator<Item = &'static str> {
+    ROOT_TRANSLATIONS.iter().map(|(locale, _)| *locale)
+}
@@ -0,0 +1,116 @@
+//! Ruby annotations of the translations, e.g. the furigana readings of Japanese kanji
+//!
+//! Uses the notation of Aozora Bunko, `漢字《かんじ》` annotates the run of kanji before the reading,
+//! and `｜` marks where the base starts when it isn't only kanji, `｜東京タワー《とうきょうタワー》`.
+//! The texts render the base without its reading, see [crate::prelude::I18n::t_ruby] for the annotated segments
+
+use std::borrow::Cow;
+
+use bevy::reflect::Reflect;
+
+/// Marks the start of the base of an annotation
+const BASE_MARKER: char = '｜';
+const READING_START: char = '《';
+const READING_END: char = '》';
+
+/// A segment of a translation and its ruby annotation, `漢字《かんじ》` -> `漢字` read as `かんじ`
+#[derive(Debug, Clone, PartialEq, Eq, Reflect)]
+pub struct RubySegment {
+    /// Text of the segment, rendered at the regular size
+    pub base: String,
+    /// Reading rendered above the base, `None` for the text between the annotations
+    pub reading: Option<String>,
+}
+
+/// Splits a translation into its plain and annotated segments,
+/// `漢字《かんじ》を読む` -> `漢字` (`かんじ`), `を読む`
+///
+/// Unclosed readings and readings without a base are kept as text
+pub(crate) fn parse_ruby(text: &str) -> Vec<RubySegment> {
+    let mut segments = vec![];
+    let mut plain = String::new();
+    let mut rest = text;
+    while let Some(index) = rest.find([BASE_MARKER, READING_START]) {
+        plain.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let marked = rest.starts_with(BASE_MARKER);
+        let annotation = if marked {
+            let base = &rest[BASE_MARKER.len_utf8()..];
+            base.split_once(READING_START)
+                .filter(|(base, _)| !base.is_empty() && !base.contains([BASE_MARKER, READING_END]))
+                .and_then(|(base, after)| Some((base.to_string(), after.split_once(READING_END)?)))
+        } else {
+            let start = plain
+                .char_indices()
+                .rev()
+                .take_while(|(_, c)| is_kanji(*c))
+                .last()
+                .map(|(index, _)| index);
+            start
+                .and_then(|start| {
+                    Some((
+                        start,
+                        rest[READING_START.len_utf8()..].split_once(READING_END)?,
+                    ))
+                })
+                .map(|(start, reading)| (plain.split_off(start), reading))
+        };
+        match annotation {
+            Some((base, (reading, after))) if !reading.is_empty() => {
+                if !plain.is_empty() {
+                    segments.push(RubySegment {
+                        base: std::mem::take(&mut plain),
+                        reading: None,
+                    });
+                }
+                segments.push(RubySegment {
+                    base,
+                    reading: Some(reading.to_string()),
+                });
+                rest = after;
+            }
+            annotation => {
+                // The kanji taken for the base go back to the text
+                if let (false, Some((base, _))) = (marked, annotation) {
+                    plain.push_str(&base);
+                }
+                let c = rest.chars().next().unwrap();
+                plain.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    plain.push_str(rest);
+    if !plain.is_empty() {
+        segments.push(RubySegment {
+            base: plain,
+            reading: None,
+        });
+    }
+    segments
+}
+
+/// Text of a translation without its ruby annotations, `漢字《かんじ》を読む` -> `漢字を読む`
+pub(crate) fn strip_ruby(text: Cow<'_, str>) -> Cow<'_, str> {
+    if !text.contains(READING_START) {
+        return text;
+    }
+    Cow::Owned(
+        parse_ruby(&text)
+            .into_iter()
+            .map(|segment| segment.base)
+            .collect(),
+    )
+}
+
+/// Whether a character can be the base of an annotation without a [BASE_MARKER],
+/// the CJK ideographs along with `々`, `〆`, `〇` and `ヶ`
+fn is_kanji(c: char) -> bool {
+    matches!(c,
+        '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{20000}'..='\u{3134F}'
+        | '々' | '〆' | '〇' | 'ヶ'
+    )
+}
@@ -0,0 +1,224 @@
+//! Select expressions of the translations, a subset of the ICU MessageFormat ones
+//!
+//! `{gender, select, male {He} female {She} other {They}} liked your post` selects the branch matching the `gender` argument,
+//! falling back to `other`. `{count, plural, =0 {No items} one {One item} other {{count} items}}` selects
+//! the exact `=n` branch of the count first, then the branch of its plural category.
+//! Expressions can be nested in the branches, whose placeholders are interpolated like the rest of the translation
+
+use std::borrow::Cow;
+
+/// Kind of a select expression
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    /// Selects on the value of the argument
+    Select,
+    /// Selects on the plural category of the count
+    Plural,
+}
+
+/// A select expression, `{name, kind, key {branch} ...}`
+struct Expression<'a> {
+    name: &'a str,
+    kind: Kind,
+    branches: Vec<(&'a str, &'a str)>,
+}
+
+/// Replaces the select expressions of a translation with their selected branch,
+/// the plural category is the one of the count of the text when provided
+///
+/// Expressions without a matching branch or an `other` one are left as-is.
+/// The translation is returned as-is, without allocating, when it has no select expression
+pub(crate) fn evaluate_selects<'a>(
+    text: Cow<'a, str>,
+    args: &[(&str, String)],
+    category: Option<&str>,
+) -> Cow<'a, str> {
+    if !text.contains("select") && !text.contains("plural") {
+        return text;
+    }
+    let mut output = String::with_capacity(text.len());
+    if !evaluate(&text, args, category, &mut output) {
+        return text;
+    }
+    Cow::Owned(output)
+}
+
+/// Writes the text with its expressions replaced to the output, `false` when it has none
+fn evaluate(
+    text: &str,
+    args: &[(&str, String)],
+    category: Option<&str>,
+    output: &mut String,
+) -> bool {
+    let mut found = false;
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let expression = closing_brace(rest).and_then(|end| Some((parse(&rest[1..end])?, end)));
+        let Some((expression, end)) = expression else {
+            output.push('{');
+            rest = &rest[1..];
+            continue;
+        };
+        match select(&expression, args, category) {
+            Some(branch) => {
+                evaluate(branch, args, category, output);
+                found = true;
+            }
+            None => {
+                bevy::log::debug!("No branch of {} matches its argument", expression.name);
+                output.push_str(&rest[..=end]);
+            }
+        }
+        rest = &rest[end + 1..];
+    }
+    output.push_str(rest);
+    found
+}
+
+/// Branch of the expression matching its argument, or the `other` branch
+fn select<'a>(
+    expression: &Expression<'a>,
+    args: &[(&str, String)],
+    category: Option<&str>,
+) -> Option<&'a str> {
+    let value = args
+        .iter()
+        .find(|(arg, _)| *arg == expression.name)
+        .map(|(_, value)| value.as_str());
+    let branch = |key: &str| {
+        expression
+            .branches
+            .iter()
+            .find(|(branch, _)| *branch == key)
+            .map(|(_, text)| *text)
+    };
+    let selected = match expression.kind {
+        Kind::Select => value.and_then(branch),
+        Kind::Plural => value
+            .and_then(|value| branch(&format!("={}", value)))
+            .or_else(|| category.and_then(branch)),
+    };
+    selected.or_else(|| branch("other"))
+}
+
+/// Parses the content of the braces of a select expression, `gender, select, male {He} other {They}`
+fn parse(content: &str) -> Option<Expression<'_>> {
+    let (name, rest) = content.split_once(',')?;
+    let (kind, mut rest) = rest.split_once(',')?;
+    let name = name.trim();
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+    if !is_identifier {
+        return None;
+    }
+    let kind = match kind.trim() {
+        "select" => Kind::Select,
+        "plural" => Kind::Plural,
+        _ => return None,
+    };
+    let mut branches = vec![];
+    while !rest.trim().is_empty() {
+        let start = rest.find('{')?;
+        let key = rest[..start].trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            return None;
+        }
+        let end = start + closing_brace(&rest[start..])?;
+        branches.push((key, &rest[start + 1..end]));
+        rest = &rest[end + 1..];
+    }
+    (!branches.is_empty()).then_some(Expression {
+        name,
+        kind,
+        branches,
+    })
+}
+
+/// Index of the brace closing the one the text starts with
+fn closing_brace(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (index, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 1 => return Some(index),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate_str(text: &str, args: &[(&str, String)], category: Option<&str>) -> String {
+        evaluate_selects(Cow::Borrowed(text), args, category).into_owned()
+    }
+
+    #[test]
+    fn selects_the_matching_branch_or_other() {
+        let text = "{gender, select, male {He} female {She} other {They}} liked your post";
+        let args = [("gender", "female".to_string())];
+        assert_eq!(evaluate_str(text, &args, None), "She liked your post");
+        let args = [("gender", "robot".to_string())];
+        assert_eq!(evaluate_str(text, &args, None), "They liked your post");
+        assert_eq!(evaluate_str(text, &[], None), "They liked your post");
+    }
+
+    #[test]
+    fn plural_prefers_the_exact_branch_over_the_category() {
+        let text = "{count, plural, =0 {No items} one {One item} other {{count} items}}";
+        let count = |count: &str| [("count", count.to_string())];
+        assert_eq!(evaluate_str(text, &count("0"), Some("other")), "No items");
+        assert_eq!(evaluate_str(text, &count("1"), Some("one")), "One item");
+        assert_eq!(
+            evaluate_str(text, &count("5"), Some("other")),
+            "{count} items"
+        );
+        assert_eq!(evaluate_str(text, &count("5"), None), "{count} items");
+    }
+
+    #[test]
+    fn nested_selects_are_evaluated_in_the_selected_branch() {
+        let text = "{gender, select, female {{count, plural, one {She has one cat} other {She has {count} cats}}} other {{count, plural, one {They have one cat} other {They have {count} cats}}}}!";
+        let args = |gender: &str| [("gender", gender.to_string()), ("count", "3".to_string())];
+        assert_eq!(
+            evaluate_str(text, &args("female"), Some("other")),
+            "She has {count} cats!"
+        );
+        assert_eq!(
+            evaluate_str(text, &args("male"), Some("one")),
+            "They have one cat!"
+        );
+    }
+
+    #[test]
+    fn text_without_expressions_is_borrowed() {
+        let args = [("name", "Bevy".to_string())];
+        for text in [
+            "Hello {name}",
+            "Select {name}",
+            "{name, select}",
+            "{a b, select, other {x}}",
+        ] {
+            let evaluated = evaluate_selects(Cow::Borrowed(text), &args, None);
+            assert!(matches!(evaluated, Cow::Borrowed(borrowed) if borrowed == text));
+        }
+    }
+
+    #[test]
+    fn expressions_without_matching_branch_are_left_as_is() {
+        let text = "{gender, select, male {He}} left, {gender, select, other {they}} stayed";
+        let args = [("gender", "female".to_string())];
+        assert_eq!(
+            evaluate_str(text, &args, None),
+            "{gender, select, male {He}} left, they stayed"
+        );
+    }
+}
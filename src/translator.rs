@@ -0,0 +1,71 @@
+use bevy::ecs::system::{Res, SystemParam};
+#[cfg(feature = "fonts")]
+use bevy::{asset::Handle, text::Font};
+
+use crate::resources::I18n;
+#[cfg(feature = "fonts")]
+use crate::resources::{FontManager, FontStyle};
+
+/// Bundles the [I18n] and [FontManager] resources to produce localized text outside of the text components
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_i18n::prelude::*;
+///
+/// fn spawn_toast(mut commands: Commands, tr: Translator) {
+///     commands.spawn((
+///         Text2d::new(tr.translate_with("messages.hello", &[("name", "Bevy User")])),
+///         TextFont {
+///             font: tr.font_for("NotoSans", tr.locale()),
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct Translator<'w> {
+    i18n: Res<'w, I18n>,
+    #[cfg(feature = "fonts")]
+    font_manager: Res<'w, FontManager>,
+}
+
+impl Translator<'_> {
+    /// Translates a key in the current locale, see [I18n::t]
+    pub fn translate(&self, key: &str) -> String {
+        self.i18n.t(key)
+    }
+
+    /// Translates a key in the current locale, replacing its `{name}` placeholders, see [I18n::t_with]
+    pub fn translate_with(&self, key: &str, args: &[(&str, impl crate::arg::ToArg)]) -> String {
+        self.i18n.t_with(key, args)
+    }
+
+    /// Translates every element of a list in the current locale, see [I18n::t_list]
+    pub fn translate_list(&self, key: &str) -> Vec<String> {
+        self.i18n.t_list(key)
+    }
+
+    #[cfg(feature = "fonts")]
+    /// Regular font of a dynamic font family for a locale, resolved like [crate::components::I18nFont]
+    ///
+    /// Bevy's default font when the family doesn't exist
+    pub fn font_for(&self, family: &str, locale: &str) -> Handle<Font> {
+        self.font_manager
+            .get(family, Some(locale.to_string()), FontStyle::Regular)
+    }
+
+    /// Current locale of the [I18n] resource
+    pub fn locale(&self) -> &str {
+        self.i18n.current()
+    }
+
+    pub fn i18n(&self) -> &I18n {
+        &self.i18n
+    }
+
+    #[cfg(feature = "fonts")]
+    pub fn font_manager(&self) -> &FontManager {
+        &self.font_manager
+    }
+}
@@ -0,0 +1,49 @@
+use bevy_simple_i18n::prelude::*;
+
+fn i18n(locale: &str) -> I18n {
+    let mut i18n = I18n::default();
+    i18n.set_locale(locale).unwrap();
+    i18n
+}
+
+#[test]
+fn key_args_are_translated_in_the_same_locale() {
+    let args = [("type", Arg::key("damage.fire"))];
+    assert_eq!(
+        i18n("en").t_with("combat.hit", &args),
+        "You take fire damage"
+    );
+    assert_eq!(
+        i18n("fr").t_with("combat.hit", &args),
+        "Vous subissez des dégâts de feu"
+    );
+}
+
+#[test]
+fn selects_branch_on_the_translated_word() {
+    let fr = i18n("fr");
+    assert_eq!(
+        fr.t_with("combat.resist", &[("type", Arg::key("damage.ice"))]),
+        "La glace ne vous fait rien"
+    );
+    assert_eq!(
+        fr.t_with("combat.resist", &[("type", Arg::key("damage.fire"))]),
+        "Le feu ne vous fait rien"
+    );
+}
+
+#[test]
+fn value_args_are_interpolated_as_is() {
+    assert_eq!(
+        i18n("en").t_with("combat.hit", &[("type", "damage.fire")]),
+        "You take damage.fire damage"
+    );
+}
+
+#[test]
+fn key_args_drop_their_direction_marks() {
+    assert_eq!(
+        i18n("ar").t_with("combat.hit", &[("type", Arg::key("damage.fire"))]),
+        "\u{200F}تتلقى ضرر نار"
+    );
+}
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use bevy_simple_i18n::prelude::*;
+
+fn app(plugin: I18nPlugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Font>()
+        .add_plugins(plugin);
+    app
+}
+
+#[test]
+fn persisted_rtl_locale_mirrors_the_layout() {
+    let path = std::env::temp_dir().join("bevy_simple_i18n_layout_direction_test");
+    std::fs::write(&path, "ar").unwrap();
+    let mut app = app(I18nPlugin::new().with_persisted_locale(&path));
+    let root = app
+        .world_mut()
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                ..default()
+            },
+            MirrorLayout,
+        ))
+        .id();
+    app.update();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(app.world().resource::<I18n>().current(), "ar");
+    assert!(app.world().resource::<LayoutDirection>().is_rtl());
+    assert_eq!(
+        app.world().get::<Node>(root).unwrap().flex_direction,
+        FlexDirection::RowReverse
+    );
+}
+
+#[test]
+fn default_locale_keeps_the_layout() {
+    let mut app = app(I18nPlugin::new());
+    app.update();
+
+    assert!(!app.world().resource::<LayoutDirection>().is_rtl());
+}
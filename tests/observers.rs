@@ -0,0 +1,73 @@
+#![cfg(feature = "observers")]
+
+use bevy::prelude::*;
+use bevy_simple_i18n::prelude::*;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Font>()
+        .add_plugins(I18nPlugin::new().with_default_locale("en"));
+    app.update();
+    app
+}
+
+#[test]
+fn texts_spawned_without_a_text_component_are_translated_on_insert() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(I18nText::new("hello")).id();
+    app.world_mut().flush();
+
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Hello World");
+}
+
+#[test]
+fn spawned_texts_are_only_translated_once() {
+    let mut app = app();
+    app.world_mut()
+        .spawn((Text::default(), I18nText::new("missing.key")));
+    app.update();
+
+    assert_eq!(app.world().resource::<I18n>().missing_keys_count(), 1);
+}
+
+#[test]
+fn texts_changed_after_their_insert_are_translated_again() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn(I18nText::new("messages.hello").with_arg("name", "Ferris"))
+        .id();
+    app.world_mut().flush();
+    app.world_mut()
+        .get_mut::<I18nText>(entity)
+        .unwrap()
+        .set_arg("name", "Bevy");
+    app.update();
+
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Hello Bevy");
+}
+
+#[test]
+fn texts_are_translated_when_the_locale_changes() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(I18nText::new("hello")).id();
+    app.update();
+    app.world_mut()
+        .resource_mut::<I18n>()
+        .set_locale("ja")
+        .unwrap();
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "こんにちは");
+
+    app.world_mut()
+        .get_mut::<I18nText>(entity)
+        .unwrap()
+        .set_arg("unused", "value");
+    app.world_mut()
+        .resource_mut::<I18n>()
+        .set_locale("en")
+        .unwrap();
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Hello World");
+}
@@ -0,0 +1,34 @@
+use bevy_simple_i18n::prelude::*;
+
+fn i18n(locale: &str, fallbacks: &[&str]) -> I18n {
+    let mut i18n = I18n::default();
+    i18n.set_locale(locale).unwrap();
+    i18n.set_fallbacks(fallbacks.iter().copied());
+    i18n
+}
+
+#[test]
+fn missing_keys_resolve_to_the_fallback_locales() {
+    let i18n = i18n("ja", &["fr", "de"]);
+    assert_eq!(i18n.t("hello"), "こんにちは");
+    assert_eq!(i18n.t("damage.fire"), "feu");
+    assert!(!i18n.is_translated_in("ja", "damage.fire"));
+}
+
+#[test]
+fn changing_the_fallbacks_resolves_the_keys_again() {
+    let mut i18n = i18n("ja", &["fr"]);
+    assert_eq!(i18n.t("damage.fire"), "feu");
+    i18n.set_fallbacks(["de"]);
+    assert_eq!(i18n.t("damage.fire"), "Feuer");
+}
+
+#[test]
+fn overrides_of_the_fallback_locales_resolve_the_keys_again() {
+    let mut i18n = i18n("ja", &["de"]);
+    assert_eq!(i18n.t("damage.fire"), "Feuer");
+    i18n.set_override("de", "damage.fire", "Flamme");
+    assert_eq!(i18n.t("damage.fire"), "Flamme");
+    i18n.clear_overrides();
+    assert_eq!(i18n.t("damage.fire"), "Feuer");
+}